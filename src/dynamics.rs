@@ -0,0 +1,117 @@
+use snafu::prelude::*;
+
+use crate::utils::{assert_finite, round};
+
+#[derive(Debug, Snafu)]
+pub enum DynamicsError {
+    #[snafu(display("The acceleration {acceleration} m/s² must be greater than zero"))]
+    NonPositiveAcceleration { acceleration: f64 },
+
+    #[snafu(display("The target speed {target_speed} m/s must not be negative"))]
+    NegativeSpeed { target_speed: f64 },
+}
+
+/// # Calculate Ground Roll to Reach a Speed
+///
+/// Assumes a constant acceleration from standstill.
+///
+/// ## Arguments
+///
+/// * `target_speed`: Speed to reach, e.g. the rotation speed, in m/s
+/// * `acceleration`: Constant acceleration in m/s²
+///
+/// returns: Result<f64, DynamicsError> Distance in m, or an error for a negative speed or an acceleration of zero or
+/// below
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::dynamics::*;
+///
+/// let distance = ground_roll_to_speed(30.0, 2.0).unwrap();
+/// ```
+pub fn ground_roll_to_speed(target_speed: f64, acceleration: f64) -> Result<f64, DynamicsError> {
+    validate_acceleration_to_speed(target_speed, acceleration)?;
+
+    Ok(round(target_speed.powi(2) / (2.0 * acceleration), 2))
+}
+
+/// # Calculate Time to Reach a Speed
+///
+/// Assumes a constant acceleration from standstill.
+///
+/// ## Arguments
+///
+/// * `target_speed`: Speed to reach, e.g. the rotation speed, in m/s
+/// * `acceleration`: Constant acceleration in m/s²
+///
+/// returns: Result<f64, DynamicsError> Time in s, or an error for a negative speed or an acceleration of zero or below
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::dynamics::*;
+///
+/// let time = time_to_speed(30.0, 2.0).unwrap();
+/// ```
+pub fn time_to_speed(target_speed: f64, acceleration: f64) -> Result<f64, DynamicsError> {
+    validate_acceleration_to_speed(target_speed, acceleration)?;
+
+    Ok(round(target_speed / acceleration, 2))
+}
+
+fn validate_acceleration_to_speed(target_speed: f64, acceleration: f64) -> Result<(), DynamicsError> {
+    assert_finite(target_speed, "target_speed");
+    assert_finite(acceleration, "acceleration");
+
+    if target_speed < 0.0 {
+        return Err(DynamicsError::NegativeSpeed { target_speed });
+    }
+
+    if acceleration <= 0.0 {
+        return Err(DynamicsError::NonPositiveAcceleration { acceleration });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ground_roll_to_30_ms_at_2_ms2() {
+        let result = ground_roll_to_speed(30.0, 2.0);
+        assert_eq!(result.unwrap(), 225.0);
+    }
+
+    #[test]
+    fn time_to_30_ms_at_2_ms2() {
+        let result = time_to_speed(30.0, 2.0);
+        assert_eq!(result.unwrap(), 15.0);
+    }
+
+    #[test]
+    fn ground_roll_at_standstill() {
+        let result = ground_roll_to_speed(0.0, 2.0);
+        assert_eq!(result.unwrap(), 0.0);
+    }
+
+    #[test]
+    fn ground_roll_without_acceleration() {
+        assert!(matches!(ground_roll_to_speed(30.0, 0.0), Err(DynamicsError::NonPositiveAcceleration { .. })));
+        assert!(matches!(ground_roll_to_speed(30.0, -2.0), Err(DynamicsError::NonPositiveAcceleration { .. })));
+    }
+
+    #[test]
+    fn time_to_speed_without_acceleration() {
+        assert!(matches!(time_to_speed(30.0, 0.0), Err(DynamicsError::NonPositiveAcceleration { .. })));
+        assert!(matches!(time_to_speed(30.0, -2.0), Err(DynamicsError::NonPositiveAcceleration { .. })));
+    }
+
+    #[test]
+    fn negative_target_speed() {
+        assert!(matches!(ground_roll_to_speed(-30.0, 2.0), Err(DynamicsError::NegativeSpeed { .. })));
+        assert!(matches!(time_to_speed(-30.0, 2.0), Err(DynamicsError::NegativeSpeed { .. })));
+    }
+}
@@ -1,12 +1,27 @@
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
 use enterpolation::{DiscreteGenerator, Generator, Sorted, SortedGenerator, utils::lerp};
 use snafu::prelude::*;
 
-use crate::meteorology::{calculate_temperature_deviation, UndefinedPressureAltitudeError};
-use crate::utils::{feet_to_meter, round};
+use crate::meteorology::{calculate_temperature_deviation, icao_temperature, UndefinedPressureAltitudeError};
+use crate::utils::{angle_to_gradient, approx_eq, assert_finite, feet_to_meter, meter_to_feet, round, round_up};
 
 const MAX_TEMP: f64 = 70.0;
 const MIN_TEMP: f64 = -90.0;
+// The temperature correction is linear in the ISA deviation, so it is only sensible within these bounds
+const MAX_ISA_DEVIATION: f64 = 60.0;
+const MIN_ISA_DEVIATION: f64 = -60.0;
 const MAX_SLOPE: f64 = 25.0;
+// The slope correction takes 10 % off per 1 % downhill. Unlike the uphill penalty this credit is not conservative, so it
+// is limited to halving the distance at 5 % instead of extrapolating the linear rule towards no distance at all at 10 %
+const MAX_DOWNHILL_SLOPE: f64 = 5.0;
+const MAX_CONTAMINATION_DEPTH: f64 = 30.0;
+const MIN_GRASS_HEIGHT: f64 = 10.0;
+const MAX_GRASS_HEIGHT: f64 = 40.0;
+const TEMPERATURE_SEARCH_TOLERANCE: f64 = 0.01;
+// Inverse of the tolerance, as dividing by a power of ten keeps the result free of floating point noise
+const TEMPERATURE_SEARCH_SCALE: f64 = 100.0;
 
 #[derive(Debug)]
 struct TakeoffDistances {
@@ -21,12 +36,24 @@ pub enum Engine {
     Rotax912Uls,
 }
 
+/// General condition of the runway. The flat `Slush`, `Snow` and `PowderSnow` factors are equivalent to a
+/// `Contaminated` runway with a depth of 10 mm of the respective contaminant.
 #[derive(Debug, Clone, Copy)]
 pub enum SurfaceCondition {
     Inconspicuous,
     Slush,
     Snow,
     PowderSnow,
+    /// Runway covered by the given contaminant, depth in mm. The penalty grows linearly with the depth, which is
+    /// limited to 30 mm. A negative or non-finite depth is rejected.
+    Contaminated { kind: ContaminantKind, depth_mm: f64 },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ContaminantKind {
+    Slush,
+    Snow,
+    PowderSnow,
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -35,6 +62,82 @@ pub struct GrassSurface {
     pub soft_ground: bool,
     pub damaged_turf: bool,
     pub high_grass: bool,
+    /// Measured grass height in cm, replaces the fixed `high_grass` factor if given. A negative or non-finite height is
+    /// rejected.
+    pub grass_height_cm: Option<f64>,
+}
+
+/// Runway with a grass section at the start and a paved section after it
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MixedSurface {
+    /// Fraction of the takeoff roll on grass from 0.0 to 1.0
+    pub grass_fraction: f64,
+    pub grass: GrassSurface,
+}
+
+/// Rounding of the calculated distances
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// To the nearest cm
+    #[default]
+    Nearest,
+    /// Up to the next full m, so the distance is never optimistic
+    Up,
+}
+
+/// Technique used to start the takeoff run
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StartTechnique {
+    /// Full power run-up against the brakes before releasing them, as assumed by the Flight Manual
+    #[default]
+    Static,
+    /// Power set while already rolling, e.g. to keep the nose wheel light on grass
+    Rolling,
+}
+
+/// Optional refinements of the takeoff calculation, all disabled by default
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TakeoffOptions {
+    /// Additional distance per 1000 ft density altitude for the power loss of the normally aspirated engine, e.g. 0.03,
+    /// not negative
+    pub engine_derate: Option<f64>,
+    /// Maximum factor all corrections combined may multiply the base distance with, e.g. 5.0, at least 1.0. Only
+    /// supported by `calculate_takeoff_report`, which flags capped distances.
+    pub max_total_factor: Option<f64>,
+    pub rounding: RoundingMode,
+    pub start_technique: StartTechnique,
+}
+
+/// Aircraft and runway conditions shared by the performance calculations
+#[derive(Debug, Clone, Copy)]
+pub struct PerformanceConditions {
+    pub engine: Engine,
+    /// Mass of the aircraft in kg
+    pub mass: f64,
+    /// Pressure altitude in ft
+    pub pressure_altitude: f64,
+    /// Temperature on the runway in °C
+    pub temperature: f64,
+    /// Slope (positive or negative) in percentage
+    pub slope: f64,
+    pub grass_surface: Option<GrassSurface>,
+    pub surface_condition: SurfaceCondition,
+}
+
+impl From<(Engine, f64, f64, f64, f64, Option<GrassSurface>, SurfaceCondition)> for PerformanceConditions {
+    fn from(
+        (engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition): (
+            Engine,
+            f64,
+            f64,
+            f64,
+            f64,
+            Option<GrassSurface>,
+            SurfaceCondition,
+        ),
+    ) -> Self {
+        PerformanceConditions { engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition }
+    }
 }
 
 #[derive(Debug, Snafu)]
@@ -51,17 +154,172 @@ pub enum TakeoffCalculationError {
     #[snafu(display("Temperature {temperature} °C is above the maximum sensible data ({max} °C)"))]
     TemperatureTooHigh { max: f64, temperature: f64 },
 
+    #[snafu(display("ISA deviation {isa_deviation} °C is below the minimum sensible deviation ({min} °C)"))]
+    IsaDeviationTooLow { min: f64, isa_deviation: f64 },
+
+    #[snafu(display("ISA deviation {isa_deviation} °C is above the maximum sensible deviation ({max} °C)"))]
+    IsaDeviationTooHigh { max: f64, isa_deviation: f64 },
+
     #[snafu(display("Slope {slope} % is is too steep to provide sensible data (Maximum {max} %)"))]
     SlopeTooSteep { max: f64, slope: f64 },
 
     #[snafu(display("The given pressure altitude is not defined by the ICAO standard atmosphere: {source}"))]
     InvalidPressureAltitude { source: UndefinedPressureAltitudeError },
+
+    #[snafu(display("The distance to 50 ft height of {required} m exceeds the available {available} m even under the most favorable searched conditions"))]
+    RunwayTooShort { available: f64, required: f64 },
+
+    #[snafu(display("Grass fraction {grass_fraction} is not within 0.0 and 1.0"))]
+    GrassFractionOutOfRange { grass_fraction: f64 },
+
+    #[snafu(display("Contamination depth {depth_mm} mm must be finite and not negative"))]
+    InvalidContaminationDepth { depth_mm: f64 },
+
+    #[snafu(display("Grass height {grass_height_cm} cm must be finite and not negative"))]
+    InvalidGrassHeight { grass_height_cm: f64 },
+
+    #[snafu(display("Engine derate {engine_derate} must be finite and not negative"))]
+    InvalidEngineDerate { engine_derate: f64 },
+
+    #[snafu(display("Maximum total factor {max_total_factor} must be finite and at least 1.0"))]
+    InvalidMaxTotalFactor { max_total_factor: f64 },
+
+    #[snafu(display("A maximum total factor is only supported by calculate_takeoff_report, which flags capped distances"))]
+    UnflaggedMaxTotalFactor,
 }
 
 pub type TakeoffResult = Result<(f64, f64), TakeoffCalculationError>;
 
+/// Takeoff run and distance to 50 ft height in m, ordered by the usually limiting distance to 50 ft
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakeoffDistance {
+    pub takeoff_run: f64,
+    pub over_50ft: f64,
+}
+
+impl TakeoffDistance {
+    /// # Find the Limiting Takeoff Distance
+    ///
+    /// ## Arguments
+    ///
+    /// * `distances`: Takeoff distances to compare
+    ///
+    /// returns: Option<&TakeoffDistance> The distance with the longest distance to 50 ft, the longer takeoff run on a
+    /// tie, or None if no distances are given
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aviation_calculator::fk9::*;
+    ///
+    /// let distances = [TakeoffDistance::from((150.0, 370.0)), TakeoffDistance::from((140.0, 380.0))];
+    /// assert_eq!(TakeoffDistance::limiting(&distances).unwrap().over_50ft, 380.0);
+    /// ```
+    pub fn limiting(distances: &[Self]) -> Option<&Self> {
+        distances.iter().reduce(|limiting, distance| if distance > limiting { distance } else { limiting })
+    }
+
+    /// # Compare Takeoff Distances with a Tolerance
+    ///
+    /// ## Arguments
+    ///
+    /// * `other`: Takeoff distance to compare with
+    /// * `epsilon`: Maximum difference of each distance in m
+    ///
+    /// returns: bool Whether both the takeoff run and the distance to 50 ft are within the tolerance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aviation_calculator::fk9::*;
+    ///
+    /// let distance = TakeoffDistance::from((168.3, 412.5));
+    /// assert!(distance.approx_matches(&TakeoffDistance::from((168.301, 412.5)), 0.01));
+    /// ```
+    pub fn approx_matches(&self, other: &Self, epsilon: f64) -> bool {
+        approx_eq(self.takeoff_run, other.takeoff_run, epsilon) && approx_eq(self.over_50ft, other.over_50ft, epsilon)
+    }
+}
+
+/// # Takeoff Distance Error Against a Reference
+///
+/// ## Arguments
+///
+/// * `computed`: Calculated takeoff distance
+/// * `reference`: Reference takeoff distance, e.g. from the Flight Manual
+///
+/// returns: (f64, f64) Percent error of the takeoff run and the distance to 50 ft, positive if the calculation is longer
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+///
+/// let (takeoff_run, over_50ft) = takeoff_distance_error(&TakeoffDistance::from((150.0, 370.0)), &TakeoffDistance::from((153.0, 375.0)));
+/// ```
+pub fn takeoff_distance_error(computed: &TakeoffDistance, reference: &TakeoffDistance) -> (f64, f64) {
+    let percent_error = |computed: f64, reference: f64| round((computed - reference) / reference * 100.0, 2);
+
+    (percent_error(computed.takeoff_run, reference.takeoff_run), percent_error(computed.over_50ft, reference.over_50ft))
+}
+
+impl PartialOrd for TakeoffDistance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.over_50ft.partial_cmp(&other.over_50ft)? {
+            Ordering::Equal => self.takeoff_run.partial_cmp(&other.takeoff_run),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl From<(f64, f64)> for TakeoffDistance {
+    fn from((takeoff_run, over_50ft): (f64, f64)) -> Self {
+        TakeoffDistance { takeoff_run, over_50ft }
+    }
+}
+
+/// Takeoff distances in m, flagged if the combined corrections were capped by `TakeoffOptions::max_total_factor`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakeoffReport {
+    pub takeoff_run: f64,
+    pub to_50_feet: f64,
+    pub capped: bool,
+}
+
+/// Multipliers applied by the takeoff calculation, 1.0 if a correction does not apply
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakeoffBreakdown {
+    pub pressure_altitude: f64,
+    pub temperature: f64,
+    pub slope: f64,
+    pub grass: f64,
+    pub surface: f64,
+}
+
+/// Takeoff distances in meters as well as in feet, e.g. for briefing cards showing both
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualUnitDistance {
+    pub ground_roll_m: f64,
+    pub ground_roll_ft: f64,
+    pub over_50ft_m: f64,
+    pub over_50ft_ft: f64,
+}
+
+impl From<(f64, f64)> for DualUnitDistance {
+    fn from((ground_roll_m, over_50ft_m): (f64, f64)) -> Self {
+        DualUnitDistance {
+            ground_roll_m,
+            ground_roll_ft: round(meter_to_feet(ground_roll_m), 2),
+            over_50ft_m,
+            over_50ft_ft: round(meter_to_feet(over_50ft_m), 2),
+        }
+    }
+}
+
 /// # Takeoff Calculation for FK9 Mk VI
 /// Calculations are based on the approved Flight Manual as well as the FSM 3/75 "Einflüsse auf die Länge der Startstrecke".
+/// The mass has to be within the data of the Flight Manual, including its limits: 472.5 kg to 540 kg for the
+/// ROTAX 912 UL and 472.5 kg to 600 kg for the ROTAX 912 ULS.
 ///
 /// ## Arguments
 ///
@@ -69,12 +327,15 @@ pub type TakeoffResult = Result<(f64, f64), TakeoffCalculationError>;
 /// * `mass`: Mass of the aircraft in kg
 /// * `pressure_altitude`: Pressure altitude in ft
 /// * `temperature`: Temperature on the runway in °C
-/// * `slope`: Slope (positive or negative) in percentage
+/// * `slope`: Slope (positive or negative) in percentage, from -5 % downhill to 25 % uphill
 /// * `grass_surface`: If grass runway, its condition
 /// * `surface_condition`: General condition of the runway
 ///
 /// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height
 ///
+/// Downhill slopes steeper than -5 % are rejected with `SlopeTooSteep`. Earlier versions accepted them down to -25 %,
+/// which shortened the distances to nothing at -10 % and made them negative below.
+///
 /// # Examples
 ///
 /// ```
@@ -92,13 +353,666 @@ pub fn calculate_takeoff_distance(
     grass_surface: Option<GrassSurface>,
     surface_condition: SurfaceCondition,
 ) -> TakeoffResult {
+    calculate_takeoff_distance_with_options(
+        engine,
+        mass,
+        pressure_altitude,
+        temperature,
+        slope,
+        grass_surface,
+        surface_condition,
+        TakeoffOptions::default(),
+    )
+}
+
+/// # Takeoff Calculation for FK9 Mk VI with Options
+/// Same as `calculate_takeoff_distance`, but allows to enable optional refinements of the calculation.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+/// * `options`: Optional refinements of the calculation, without `max_total_factor`
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height, or `UnflaggedMaxTotalFactor` if a
+/// cap is set, as the plain distances could not flag it. Use `calculate_takeoff_report` instead.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let options = TakeoffOptions { engine_derate: Some(0.03), ..TakeoffOptions::default() };
+/// let distances: (f64, f64) = calculate_takeoff_distance_with_options(Rotax912Uls, 525.0, 5000.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous, options).unwrap();
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_takeoff_distance_with_options(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    slope: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+    options: TakeoffOptions,
+) -> TakeoffResult {
+    if options.max_total_factor.is_some() {
+        return Err(TakeoffCalculationError::UnflaggedMaxTotalFactor);
+    }
+
+    let conditions = PerformanceConditions { engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition };
+
+    takeoff_distance(&conditions, options)
+}
+
+/// # Takeoff Calculation for FK9 Mk VI on a Mixed Surface
+/// Same as `calculate_takeoff_distance`, but for a runway which is grass for the first portion and paved after. The roll
+/// is split at the transition: the grass corrections lower the acceleration on the grass portion only, and the paved
+/// portion starts with the speed gained on the grass. The resulting multiplier scales the distance to 50 ft height like
+/// the grass corrections do, so a fraction of 1.0 matches the full grass runway.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `mixed_surface`: Fraction of the roll on grass and the condition of the grass
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height, or an error if the grass fraction
+/// is not within 0.0 and 1.0
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let mixed_surface = MixedSurface { grass_fraction: 0.5, grass: GrassSurface { wet: true, ..GrassSurface::default() } };
+/// let distances: (f64, f64) = calculate_takeoff_distance_mixed_surface(Rotax912Uls, 525.0, 100.0, 21.3, 0.0, mixed_surface, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_mixed_surface(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    slope: f64,
+    mixed_surface: MixedSurface,
+    surface_condition: SurfaceCondition,
+) -> TakeoffResult {
+    assert_finite(mixed_surface.grass_fraction, "grass_fraction");
+
+    if !(0.0..=1.0).contains(&mixed_surface.grass_fraction) {
+        return Err(TakeoffCalculationError::GrassFractionOutOfRange { grass_fraction: mixed_surface.grass_fraction });
+    }
+
+    let grass_surface = Some(mixed_surface.grass);
+    let conditions = PerformanceConditions { engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition };
+    let (takeoff_table, mut corrections) = validated_conditions(&conditions, &TakeoffOptions::default())?;
+    corrections.grass_fraction = mixed_surface.grass_fraction;
+
+    Ok(corrected_takeoff_distances(base_takeoff_distances(takeoff_table, mass), &corrections))
+}
+
+/// # Unrounded Takeoff Calculation for FK9 Mk VI
+/// Same as `calculate_takeoff_distance`, but returns the distances in full precision, e.g. to chain further
+/// corrections or to compare against reference values.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let distances: (f64, f64) = calculate_takeoff_distance_raw(Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_raw(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    slope: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> TakeoffResult {
+    let conditions = PerformanceConditions { engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition };
+    let (takeoff_table, corrections) = validated_conditions(&conditions, &TakeoffOptions::default())?;
+    let (takeoff_run, to_50_feet) = base_takeoff_distances(takeoff_table, mass);
+
+    Ok((corrections.apply_raw(takeoff_run), corrections.apply_raw(to_50_feet)))
+}
+
+/// # Maximum Temperature for a Runway for FK9 Mk VI
+/// Bisects the sensible temperature range for the highest temperature at which the distance to 50 ft height still fits
+/// the available distance. The result is rounded down to 0.01 °C, so it is at most that far below the exact limit.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `available_distance`: Available takeoff distance in m
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<f64, TakeoffCalculationError> Highest temperature on the runway in °C, or an error if the distance
+/// does not even fit at the minimum temperature
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let temperature: f64 = max_temperature_for_runway(Rotax912Uls, 600.0, 0.0, 400.0, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn max_temperature_for_runway(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    available_distance: f64,
+    slope: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> Result<f64, TakeoffCalculationError> {
+    assert_finite(available_distance, "available_distance");
+
+    let to_50_feet = |temperature: f64| {
+        calculate_takeoff_distance(engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition)
+            .map(|(_, to_50_feet)| to_50_feet)
+    };
+
+    let isa_temperature = icao_temperature(feet_to_meter(pressure_altitude)).context(InvalidPressureAltitudeSnafu)?;
+    let min_temperature = (isa_temperature + MIN_ISA_DEVIATION).max(MIN_TEMP);
+    let max_temperature = (isa_temperature + MAX_ISA_DEVIATION).min(MAX_TEMP);
+
+    let required = to_50_feet(min_temperature)?;
+    if required > available_distance {
+        return Err(TakeoffCalculationError::RunwayTooShort { available: available_distance, required });
+    }
+
+    if to_50_feet(max_temperature)? <= available_distance {
+        return Ok(max_temperature);
+    }
+
+    // The distance only grows with the temperature, so the limit always stays between both bounds
+    let (mut fits, mut too_long) = (min_temperature, max_temperature);
+    while too_long - fits > TEMPERATURE_SEARCH_TOLERANCE / 2.0 {
+        let temperature = (fits + too_long) / 2.0;
+        if to_50_feet(temperature)? <= available_distance {
+            fits = temperature;
+        } else {
+            too_long = temperature;
+        }
+    }
+
+    Ok((fits * TEMPERATURE_SEARCH_SCALE).floor() / TEMPERATURE_SEARCH_SCALE)
+}
+
+/// # Maximum Slope for a Runway for FK9 Mk VI
+/// Searches upward in steps of 0.1 % from the steepest supported downhill slope (-5 %) for the steepest slope at which
+/// the distance to 50 ft height still fits the available distance.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `available_distance`: Available takeoff distance in m
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<f64, TakeoffCalculationError> Steepest slope in percentage, or `RunwayTooShort` if the distance
+/// does not even fit at the steepest supported downhill slope
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let slope: f64 = max_slope_for_runway(Rotax912Uls, 600.0, 0.0, 15.0, 400.0, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn max_slope_for_runway(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    available_distance: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> Result<f64, TakeoffCalculationError> {
+    assert_finite(available_distance, "available_distance");
+
+    let to_50_feet = |slope: f64| {
+        calculate_takeoff_distance(engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition)
+            .map(|(_, to_50_feet)| to_50_feet)
+    };
+
+    let required = to_50_feet(-MAX_DOWNHILL_SLOPE)?;
+    if required > available_distance {
+        return Err(TakeoffCalculationError::RunwayTooShort { available: available_distance, required });
+    }
+
+    // Rounded to the step, so the accumulated float error does not miss the maximum slope
+    let mut slope = -MAX_DOWNHILL_SLOPE;
+    while slope < MAX_SLOPE && to_50_feet(round(slope + 0.1, 1))? <= available_distance {
+        slope = round(slope + 0.1, 1);
+    }
+
+    Ok(slope)
+}
+
+/// # Takeoff Calculation for FK9 Mk VI by Performance Conditions
+/// Same as `calculate_takeoff_distance`, but takes the conditions bundled in one struct.
+///
+/// ## Arguments
+///
+/// * `conditions`: Aircraft and runway conditions of the takeoff
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let conditions = PerformanceConditions::from((Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous));
+/// let distances: (f64, f64) = calculate_takeoff_distance_by_conditions(&conditions).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_by_conditions(conditions: &PerformanceConditions) -> TakeoffResult {
+    takeoff_distance(conditions, TakeoffOptions::default())
+}
+
+fn takeoff_distance(conditions: &PerformanceConditions, options: TakeoffOptions) -> TakeoffResult {
+    let (takeoff_table, corrections) = validated_conditions(conditions, &options)?;
+
+    Ok(corrected_takeoff_distances(base_takeoff_distances(takeoff_table, conditions.mass), &corrections))
+}
+
+/// # Takeoff Report for FK9 Mk VI
+/// Same as `calculate_takeoff_distance_with_options`, but also reports whether the combined corrections were capped.
+///
+/// ## Arguments
+///
+/// * `conditions`: Aircraft and runway conditions of the takeoff
+/// * `options`: Optional refinements of the calculation
+///
+/// returns: Result<TakeoffReport, TakeoffCalculationError> Takeoff run, to 50 ft Height and whether they were capped
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let conditions = PerformanceConditions::from((Rotax912Uls, 525.0, 8000.0, 30.0, 2.0, None, SurfaceCondition::Snow));
+/// let options = TakeoffOptions { max_total_factor: Some(5.0), ..TakeoffOptions::default() };
+/// let report = calculate_takeoff_report(&conditions, options).unwrap();
+/// ```
+pub fn calculate_takeoff_report(conditions: &PerformanceConditions, options: TakeoffOptions) -> Result<TakeoffReport, TakeoffCalculationError> {
+    let (takeoff_table, corrections) = validated_conditions(conditions, &options)?;
+    let (takeoff_run, to_50_feet) = corrected_takeoff_distances(base_takeoff_distances(takeoff_table, conditions.mass), &corrections);
+
+    Ok(TakeoffReport { takeoff_run, to_50_feet, capped: corrections.is_capped() })
+}
+
+/// # Takeoff Correction Breakdown for FK9 Mk VI
+/// The multipliers the takeoff calculation applies for the given conditions.
+///
+/// ## Arguments
+///
+/// * `conditions`: Aircraft and runway conditions of the takeoff
+///
+/// returns: Result<TakeoffBreakdown, TakeoffCalculationError> Multiplier of each correction
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let conditions = PerformanceConditions::from((Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous));
+/// let breakdown = takeoff_breakdown(&conditions).unwrap();
+/// ```
+pub fn takeoff_breakdown(conditions: &PerformanceConditions) -> Result<TakeoffBreakdown, TakeoffCalculationError> {
+    let (_, corrections) = validated_conditions(conditions, &TakeoffOptions::default())?;
+
+    Ok(corrections.breakdown())
+}
+
+/// # Dominant Takeoff Correction
+///
+/// ## Arguments
+///
+/// * `breakdown`: Multipliers of a takeoff calculation
+///
+/// returns: &str The correction with the largest multiplier, one of "altitude", "temperature", "slope", "grass" or
+/// "surface". On a tie the first one in this order is returned.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let conditions = PerformanceConditions::from((Rotax912Uls, 525.0, 100.0, 21.3, 3.0, None, SurfaceCondition::Inconspicuous));
+/// let dominant = dominant_correction(&takeoff_breakdown(&conditions).unwrap());
+/// assert_eq!(dominant, "slope");
+/// ```
+pub fn dominant_correction(breakdown: &TakeoffBreakdown) -> &'static str {
+    let corrections = [
+        ("altitude", breakdown.pressure_altitude),
+        ("temperature", breakdown.temperature),
+        ("slope", breakdown.slope),
+        ("grass", breakdown.grass),
+        ("surface", breakdown.surface),
+    ];
+
+    // Fully qualified, as the generator traits of enterpolation also provide `into_iter` for arrays
+    IntoIterator::into_iter(corrections)
+        .reduce(|dominant, correction| if correction.1 > dominant.1 { correction } else { dominant })
+        .map(|(name, _)| name)
+        .unwrap()
+}
+
+fn validated_conditions(conditions: &PerformanceConditions, options: &TakeoffOptions) -> Result<(&'static TakeoffDistances, Corrections), TakeoffCalculationError> {
+    assert_finite(conditions.mass, "mass");
+    assert_finite(conditions.pressure_altitude, "pressure_altitude");
+    assert_finite(conditions.temperature, "temperature");
+    assert_finite(conditions.slope, "slope");
+
+    validate_temperature(conditions.temperature)?;
+    validate_grass_surface(conditions.grass_surface)?;
+    validate_surface_condition(conditions.surface_condition)?;
+    validate_options(options)?;
+    let takeoff_table = validated_takeoff_distances(conditions.engine, conditions.mass, conditions.slope)?;
+    let temperature_deviation = validated_temperature_deviation(conditions.pressure_altitude, conditions.temperature)?;
+
+    let corrections = Corrections::new(
+        conditions.pressure_altitude,
+        temperature_deviation,
+        conditions.slope,
+        conditions.grass_surface,
+        conditions.surface_condition,
+        options,
+    );
+
+    Ok((takeoff_table, corrections))
+}
+
+/// # Takeoff Calculation for FK9 Mk VI with Slope in Degrees
+/// Same as `calculate_takeoff_distance`, but takes the slope as an angle, e.g. from charts giving it in degrees.
+/// The angle is converted to the percentage the correction is defined for, about 1.15° equal 2 %.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `slope_degrees`: Slope (positive or negative) in degrees
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let distances: (f64, f64) = calculate_takeoff_distance_slope_deg(Rotax912Uls, 525.0, 100.0, 21.3, 1.15, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_slope_deg(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    slope_degrees: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> TakeoffResult {
+    assert_finite(slope_degrees, "slope_degrees");
+
+    calculate_takeoff_distance(engine, mass, pressure_altitude, temperature, angle_to_gradient(slope_degrees), grass_surface, surface_condition)
+}
+
+/// # Takeoff Calculation for FK9 Mk VI in Meters and Feet
+/// Same as `calculate_takeoff_distance`, but returns the distances in meters as well as in feet.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<DualUnitDistance, TakeoffCalculationError> Takeoff run and to 50 ft Height, each in m and ft
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let distances: DualUnitDistance = calculate_takeoff_distance_dual(Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_dual(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    temperature: f64,
+    slope: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> Result<DualUnitDistance, TakeoffCalculationError> {
+    calculate_takeoff_distance(engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition).map(DualUnitDistance::from)
+}
+
+/// # Takeoff Calculation for FK9 Mk VI by ISA Deviation
+/// Same as `calculate_takeoff_distance`, but takes the deviation from the ICAO standard temperature directly instead of
+/// the temperature on the runway, e.g. to plan for a forecast "ISA+10" day.
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `isa_deviation`: Deviation from the ICAO standard temperature in °C
+/// * `slope`: Slope (positive or negative) in percentage
+/// * `grass_surface`: If grass runway, its condition
+/// * `surface_condition`: General condition of the runway
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Takeoff run, to 50 ft Height
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let distances: (f64, f64) = calculate_takeoff_distance_by_isa_deviation(Rotax912Uls, 525.0, 100.0, 10.0, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+/// ```
+pub fn calculate_takeoff_distance_by_isa_deviation(
+    engine: Engine,
+    mass: f64,
+    pressure_altitude: f64,
+    isa_deviation: f64,
+    slope: f64,
+    grass_surface: Option<GrassSurface>,
+    surface_condition: SurfaceCondition,
+) -> TakeoffResult {
+    assert_finite(mass, "mass");
+    assert_finite(pressure_altitude, "pressure_altitude");
+    assert_finite(isa_deviation, "isa_deviation");
+    assert_finite(slope, "slope");
+
+    validate_grass_surface(grass_surface)?;
+    validate_surface_condition(surface_condition)?;
+    let takeoff_table = validated_takeoff_distances(engine, mass, slope)?;
+    icao_temperature(feet_to_meter(pressure_altitude)).context(InvalidPressureAltitudeSnafu)?;
+    validate_isa_deviation(isa_deviation)?;
+
+    let corrections = Corrections::new(pressure_altitude, isa_deviation, slope, grass_surface, surface_condition, &TakeoffOptions::default());
+
+    Ok(corrected_takeoff_distances(base_takeoff_distances(takeoff_table, mass), &corrections))
+}
+
+/// # Environmental Correction Multipliers for FK9 Mk VI
+/// The multipliers the takeoff calculation applies for the pressure altitude and the temperature.
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude in ft
+/// * `temperature`: Temperature on the runway in °C
+///
+/// returns: Result<(f64, f64), TakeoffCalculationError> Pressure altitude multiplier, temperature multiplier
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+///
+/// let (altitude, temperature) = environmental_multipliers(3000.0, 15.0).unwrap();
+/// ```
+pub fn environmental_multipliers(pressure_altitude: f64, temperature: f64) -> Result<(f64, f64), TakeoffCalculationError> {
+    assert_finite(pressure_altitude, "pressure_altitude");
+    assert_finite(temperature, "temperature");
+
+    validate_temperature(temperature)?;
+    let temperature_deviation = validated_temperature_deviation(pressure_altitude, temperature)?;
+
+    Ok((pressure_altitude_multiplier(pressure_altitude), temperature_multiplier(temperature_deviation)))
+}
+
+/// # Mass Interpolation Detail for FK9 Mk VI
+/// The neighboring masses of the Flight Manual table the takeoff distances are interpolated between, e.g. for a
+/// tooltip like "interpolated 50 % between 540 kg and 570 kg".
+///
+/// ## Arguments
+///
+/// * `engine`:Engine of the aircraft one of ROTAX 912 UL or ROTAX 912 ULS
+/// * `mass`: Mass of the aircraft in kg
+///
+/// returns: Result<(f64, f64, f64), TakeoffCalculationError> Lower mass in kg, upper mass in kg and the factor from 0
+/// at the lower to 1 at the upper mass
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fk9::*;
+/// use aviation_calculator::fk9::Engine::Rotax912Uls;
+///
+/// let (lower, upper, factor) = interpolation_detail(Rotax912Uls, 555.0).unwrap();
+/// ```
+pub fn interpolation_detail(engine: Engine, mass: f64) -> Result<(f64, f64, f64), TakeoffCalculationError> {
+    assert_finite(mass, "mass");
+
+    let masses = &validated_takeoff_distances(engine, mass, 0.0)?.mass;
+    let (min_index, max_index, factor) = masses.upper_border(mass);
+
+    Ok((masses.gen(min_index), masses.gen(max_index), factor))
+}
+
+fn validate_temperature(temperature: f64) -> Result<(), TakeoffCalculationError> {
     if temperature > MAX_TEMP {
         return Err(TakeoffCalculationError::TemperatureTooHigh { max: MAX_TEMP, temperature });
     } else if temperature < MIN_TEMP {
         return Err(TakeoffCalculationError::TemperatureTooLow { min: MIN_TEMP, temperature });
     }
 
-    if slope > MAX_SLOPE || slope < -MAX_SLOPE {
+    Ok(())
+}
+
+fn validate_grass_surface(grass_surface: Option<GrassSurface>) -> Result<(), TakeoffCalculationError> {
+    if let Some(grass_height_cm) = grass_surface.and_then(|grass_surface| grass_surface.grass_height_cm) {
+        if !grass_height_cm.is_finite() || grass_height_cm < 0.0 {
+            return Err(TakeoffCalculationError::InvalidGrassHeight { grass_height_cm });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_surface_condition(surface_condition: SurfaceCondition) -> Result<(), TakeoffCalculationError> {
+    if let SurfaceCondition::Contaminated { depth_mm, .. } = surface_condition {
+        if !depth_mm.is_finite() || depth_mm < 0.0 {
+            return Err(TakeoffCalculationError::InvalidContaminationDepth { depth_mm });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_options(options: &TakeoffOptions) -> Result<(), TakeoffCalculationError> {
+    if let Some(engine_derate) = options.engine_derate {
+        if !engine_derate.is_finite() || engine_derate < 0.0 {
+            return Err(TakeoffCalculationError::InvalidEngineDerate { engine_derate });
+        }
+    }
+
+    if let Some(max_total_factor) = options.max_total_factor {
+        // A cap below 1.0 would shorten even the uncorrected chart distance
+        if !max_total_factor.is_finite() || max_total_factor < 1.0 {
+            return Err(TakeoffCalculationError::InvalidMaxTotalFactor { max_total_factor });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_isa_deviation(isa_deviation: f64) -> Result<(), TakeoffCalculationError> {
+    if isa_deviation > MAX_ISA_DEVIATION {
+        return Err(TakeoffCalculationError::IsaDeviationTooHigh { max: MAX_ISA_DEVIATION, isa_deviation });
+    } else if isa_deviation < MIN_ISA_DEVIATION {
+        return Err(TakeoffCalculationError::IsaDeviationTooLow { min: MIN_ISA_DEVIATION, isa_deviation });
+    }
+
+    Ok(())
+}
+
+// Validates the actual ISA deviation, but returns the one the correction is based on
+fn validated_temperature_deviation(pressure_altitude: f64, temperature: f64) -> Result<f64, TakeoffCalculationError> {
+    let isa_deviation = calculate_temperature_deviation(feet_to_meter(pressure_altitude), temperature).context(InvalidPressureAltitudeSnafu)?;
+    validate_isa_deviation(isa_deviation)?;
+
+    calculate_temperature_deviation_for_correction(pressure_altitude, temperature)
+}
+
+fn validated_takeoff_distances(engine: Engine, mass: f64, slope: f64) -> Result<&'static TakeoffDistances, TakeoffCalculationError> {
+    if slope < -MAX_DOWNHILL_SLOPE {
+        return Err(TakeoffCalculationError::SlopeTooSteep { max: -MAX_DOWNHILL_SLOPE, slope });
+    }
+
+    if slope > MAX_SLOPE {
         return Err(TakeoffCalculationError::SlopeTooSteep { max: MAX_SLOPE, slope });
     }
 
@@ -106,41 +1020,64 @@ pub fn calculate_takeoff_distance(
     let min: f64 = takeoff_table.mass.first().unwrap();
     let max: f64 = takeoff_table.mass.last().unwrap();
 
+    // Both limits are part of the table and therefore valid
     if mass < min {
         return Err(TakeoffCalculationError::MassTooLow { min, mass });
     } else if mass > max {
         return Err(TakeoffCalculationError::MassTooHigh { max, mass });
     }
 
-    Ok((apply_corrections(
+    Ok(takeoff_table)
+}
+
+fn base_takeoff_distances(takeoff_table: &TakeoffDistances, mass: f64) -> (f64, f64) {
+    (
         calculate_base_distance(mass, &takeoff_table.mass, &takeoff_table.takeoff_run),
-        pressure_altitude,
-        temperature,
-        slope,
-        grass_surface,
-        surface_condition,
-    )?, apply_corrections(
         calculate_base_distance(mass, &takeoff_table.mass, &takeoff_table.to_50_feet),
-        pressure_altitude,
-        temperature,
-        slope,
-        grass_surface,
-        surface_condition,
-    )?))
+    )
+}
+
+fn corrected_takeoff_distances((takeoff_run, to_50_feet): (f64, f64), corrections: &Corrections) -> (f64, f64) {
+    (corrections.apply(takeoff_run), corrections.apply(to_50_feet))
 }
 
-fn takeoff_distances_by_engine(engine: Engine) -> TakeoffDistances {
+fn takeoff_distances_by_engine(engine: Engine) -> &'static TakeoffDistances {
+    static ROTAX_912_UL: OnceLock<TakeoffDistances> = OnceLock::new();
+    static ROTAX_912_ULS: OnceLock<TakeoffDistances> = OnceLock::new();
+
     match engine {
-        Engine::Rotax912Ul => TakeoffDistances {
-            mass: Sorted::new_unchecked(vec![472.5, 525.0, 540.0]),
-            takeoff_run: Sorted::new_unchecked(vec![106.0, 140.0, 147.0]),
-            to_50_feet: Sorted::new_unchecked(vec![265.0, 350.0, 367.0]),
-        },
-        Engine::Rotax912Uls => TakeoffDistances {
-            mass: Sorted::new_unchecked(vec![472.5, 525.0, 540.0, 570.0, 600.0]),
-            takeoff_run: Sorted::new_unchecked(vec![100.0, 128.0, 136.0, 141.0, 153.0]),
-            to_50_feet: Sorted::new_unchecked(vec![225.0, 320.0, 338.0, 352.0, 375.0]),
-        },
+        Engine::Rotax912Ul => ROTAX_912_UL.get_or_init(|| parse_takeoff_distances(include_str!("data/fk9_rotax_912_ul.csv"))),
+        Engine::Rotax912Uls => ROTAX_912_ULS.get_or_init(|| parse_takeoff_distances(include_str!("data/fk9_rotax_912_uls.csv"))),
+    }
+}
+
+// The tables are embedded at build time, so invalid data is a programming error and panics
+fn parse_takeoff_distances(csv: &str) -> TakeoffDistances {
+    let mut mass: Vec<f64> = Vec::new();
+    let mut takeoff_run = Vec::new();
+    let mut to_50_feet = Vec::new();
+
+    for (index, line) in csv.lines().enumerate().skip(1).filter(|(_, line)| !line.trim().is_empty()) {
+        let values: Vec<f64> = line
+            .split(',')
+            .map(|value| value.trim().parse().unwrap_or_else(|_| panic!("Invalid number in line {} of takeoff table: {line}", index + 1)))
+            .collect();
+
+        let [row_mass, row_takeoff_run, row_to_50_feet] = values[..] else {
+            panic!("Expected 3 columns in line {} of takeoff table: {line}", index + 1);
+        };
+
+        assert!(mass.last().is_none_or(|last| row_mass > last), "Masses of takeoff table must be strictly increasing");
+
+        mass.push(row_mass);
+        takeoff_run.push(row_takeoff_run);
+        to_50_feet.push(row_to_50_feet);
+    }
+
+    TakeoffDistances {
+        mass: Sorted::new(mass).expect("Masses of takeoff table must be increasing"),
+        takeoff_run: Sorted::new(takeoff_run).expect("Takeoff runs of takeoff table must be increasing"),
+        to_50_feet: Sorted::new(to_50_feet).expect("Distances to 50 ft of takeoff table must be increasing"),
     }
 }
 
@@ -157,30 +1094,116 @@ fn calculate_base_distance(
     lerp(min, max, factor) / 120.0 * 100.0
 }
 
-fn apply_corrections(
-    mut takeoff_distance: f64,
+/// Correction factors for one set of conditions, computed once and applied to each distance of the table
+#[derive(Debug, Clone, Copy)]
+struct Corrections {
     pressure_altitude: f64,
     temperature: f64,
+    engine_derate: f64,
     slope: f64,
+    start_technique: f64,
     grass_surface: Option<GrassSurface>,
-    surface_condition: SurfaceCondition,
-) -> Result<f64, TakeoffCalculationError> {
-    takeoff_distance = apply_environmental_corrections(takeoff_distance, pressure_altitude, temperature)?;
-    takeoff_distance *= 1.0 + 0.1 * slope;
+    /// Fraction of the roll on grass, the rest is paved
+    grass_fraction: f64,
+    surface_condition: f64,
+    max_total_factor: Option<f64>,
+    rounding: RoundingMode,
+}
 
-    if grass_surface.is_some() {
-        takeoff_distance = apply_grass_surface_corrections(
-            takeoff_distance,
-            grass_surface.unwrap(),
-        );
+impl Corrections {
+    fn new(
+        pressure_altitude: f64,
+        temperature_deviation: f64,
+        slope: f64,
+        grass_surface: Option<GrassSurface>,
+        surface_condition: SurfaceCondition,
+        options: &TakeoffOptions,
+    ) -> Self {
+        Corrections {
+            pressure_altitude: pressure_altitude_multiplier(pressure_altitude),
+            temperature: temperature_multiplier(temperature_deviation),
+            engine_derate: options.engine_derate.map_or(1.0, |engine_derate| {
+                engine_derate_multiplier(pressure_altitude, temperature_deviation, engine_derate)
+            }),
+            slope: 1.0 + 0.1 * slope,
+            // Conservative estimate, as the power is not fully available at the start of the run
+            start_technique: match options.start_technique {
+                StartTechnique::Static => 1.0,
+                StartTechnique::Rolling => 1.05,
+            },
+            grass_surface,
+            grass_fraction: 1.0,
+            surface_condition: match surface_condition {
+                SurfaceCondition::Inconspicuous => 1.0,
+                SurfaceCondition::Slush => 1.3,
+                SurfaceCondition::Snow => 1.5,
+                SurfaceCondition::PowderSnow => 1.25,
+                SurfaceCondition::Contaminated { kind, depth_mm } => contamination_multiplier(kind, depth_mm),
+            },
+            max_total_factor: options.max_total_factor,
+            rounding: options.rounding,
+        }
+    }
+
+    fn breakdown(&self) -> TakeoffBreakdown {
+        TakeoffBreakdown {
+            pressure_altitude: self.pressure_altitude,
+            temperature: self.temperature,
+            slope: self.slope,
+            grass: self.grass_multiplier(),
+            surface: self.surface_condition,
+        }
     }
 
-    Ok(round(match surface_condition {
-        SurfaceCondition::Inconspicuous => takeoff_distance,
-        SurfaceCondition::Slush => takeoff_distance * 1.3,
-        SurfaceCondition::Snow => takeoff_distance * 1.5,
-        SurfaceCondition::PowderSnow => takeoff_distance * 1.25,
-    }, 2))
+    fn apply(&self, takeoff_distance: f64) -> f64 {
+        match self.rounding {
+            RoundingMode::Nearest => round(self.apply_raw(takeoff_distance), 2),
+            RoundingMode::Up => round_up(self.apply_raw(takeoff_distance), 0),
+        }
+    }
+
+    fn apply_raw(&self, takeoff_distance: f64) -> f64 {
+        match self.max_total_factor {
+            Some(max_total_factor) if self.is_capped() => takeoff_distance * max_total_factor,
+            _ => self.apply_uncapped(takeoff_distance),
+        }
+    }
+
+    fn is_capped(&self) -> bool {
+        self.max_total_factor.is_some_and(|max_total_factor| self.apply_uncapped(1.0) > max_total_factor)
+    }
+
+    fn apply_uncapped(&self, mut takeoff_distance: f64) -> f64 {
+        takeoff_distance *= self.pressure_altitude;
+        takeoff_distance *= self.temperature;
+        takeoff_distance *= self.engine_derate;
+        takeoff_distance *= self.slope;
+        takeoff_distance *= self.start_technique;
+        takeoff_distance *= self.grass_multiplier();
+
+        takeoff_distance * self.surface_condition
+    }
+
+    // The roll is proportional to the square of the speed over the acceleration, which the grass lowers by its
+    // multiplier. Solving for the roll with its first fraction on grass gives multiplier / (multiplier * (1 - fraction)
+    // + fraction), which is exactly 1 without grass and exactly the multiplier for a full grass runway.
+    fn grass_multiplier(&self) -> f64 {
+        self.grass_surface.map_or(1.0, |grass_surface| {
+            let multiplier = apply_grass_surface_corrections(1.0, grass_surface);
+
+            multiplier / (multiplier * (1.0 - self.grass_fraction) + self.grass_fraction)
+        })
+    }
+}
+
+fn contamination_multiplier(kind: ContaminantKind, depth_mm: f64) -> f64 {
+    let per_mm = match kind {
+        ContaminantKind::Slush => 0.03,
+        ContaminantKind::Snow => 0.05,
+        ContaminantKind::PowderSnow => 0.025,
+    };
+
+    1.0 + per_mm * depth_mm.min(MAX_CONTAMINATION_DEPTH)
 }
 
 fn apply_grass_surface_corrections(mut takeoff_distance: f64, grass_surface: GrassSurface) -> f64 {
@@ -198,29 +1221,25 @@ fn apply_grass_surface_corrections(mut takeoff_distance: f64, grass_surface: Gra
         takeoff_distance *= 1.1;
     }
 
-    if grass_surface.high_grass {
-        takeoff_distance *= 1.2;
+    match grass_surface.grass_height_cm {
+        Some(grass_height_cm) => takeoff_distance *= grass_height_multiplier(grass_height_cm),
+        None if grass_surface.high_grass => takeoff_distance *= 1.2,
+        None => {}
     }
 
     takeoff_distance
 }
 
-fn apply_environmental_corrections(
-    takeoff_distance: f64,
-    pressure_altitude: f64,
-    temperature: f64,
-) -> Result<f64, TakeoffCalculationError> {
-    let distance = apply_pressure_altitude_correction(takeoff_distance, pressure_altitude);
-    let temperature_deviation = calculate_temperature_deviation_for_correction(pressure_altitude, temperature)?;
-
-    Ok(apply_temperature_correction(distance, temperature_deviation))
+// 2% per cm above 10 cm, limited to 40 cm; 20 cm equals the fixed high grass factor
+fn grass_height_multiplier(grass_height_cm: f64) -> f64 {
+    1.0 + 0.02 * (grass_height_cm - MIN_GRASS_HEIGHT).clamp(0.0, MAX_GRASS_HEIGHT - MIN_GRASS_HEIGHT)
 }
 
 fn calculate_temperature_deviation_for_correction(pressure_altitude: f64, temperature: f64) -> Result<f64, TakeoffCalculationError> {
-    Ok(calculate_temperature_deviation(feet_to_meter(pressure_altitude), temperature.max(0.0)).context(InvalidPressureAltitudeSnafu)?)
+    calculate_temperature_deviation(feet_to_meter(pressure_altitude), temperature.max(0.0)).context(InvalidPressureAltitudeSnafu)
 }
 
-fn apply_pressure_altitude_correction(takeoff_distance: f64, pressure_altitude: f64) -> f64 {
+fn pressure_altitude_multiplier(pressure_altitude: f64) -> f64 {
     let multiplier = if pressure_altitude > 3000.0 {
         0.18
     } else if pressure_altitude > 1000.0 {
@@ -229,17 +1248,124 @@ fn apply_pressure_altitude_correction(takeoff_distance: f64, pressure_altitude:
         0.10
     };
 
-    takeoff_distance * (1.0 + multiplier * (pressure_altitude / 1000.0)).max(1.0)
+    (1.0 + multiplier * (pressure_altitude / 1000.0)).max(1.0)
+}
+
+fn temperature_multiplier(temperature_deviation: f64) -> f64 {
+    1.0 + 0.01 * temperature_deviation
 }
 
-fn apply_temperature_correction(takeoff_distance: f64, temperature_deviation: f64) -> f64 {
-    takeoff_distance * (1.0 + 0.01 * temperature_deviation)
+fn engine_derate_multiplier(pressure_altitude: f64, temperature_deviation: f64, engine_derate: f64) -> f64 {
+    // Common approximation of the density altitude: 120 ft per °C deviation from ISA
+    let density_altitude = pressure_altitude + 120.0 * temperature_deviation;
+
+    1.0 + engine_derate * density_altitude.max(0.0) / 1000.0
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
+    use crate::utils::gradient_to_angle;
+
     use super::*;
 
+    // Tolerance of the calculated distances, below the rounding to cm
+    const EPSILON: f64 = 0.01;
+
+    fn assert_takeoff_distance(result: TakeoffResult, expected: (f64, f64)) {
+        let result = TakeoffDistance::from(result.unwrap());
+        assert!(result.approx_matches(&TakeoffDistance::from(expected), EPSILON), "{result:?} does not match {expected:?}");
+    }
+
+    fn apply_corrections(
+        takeoff_distance: f64,
+        pressure_altitude: f64,
+        temperature: f64,
+        slope: f64,
+        grass_surface: Option<GrassSurface>,
+        surface_condition: SurfaceCondition,
+    ) -> Result<f64, TakeoffCalculationError> {
+        Ok(corrections(pressure_altitude, temperature, slope, grass_surface, surface_condition)?.apply(takeoff_distance))
+    }
+
+    fn corrections(
+        pressure_altitude: f64,
+        temperature: f64,
+        slope: f64,
+        grass_surface: Option<GrassSurface>,
+        surface_condition: SurfaceCondition,
+    ) -> Result<Corrections, TakeoffCalculationError> {
+        let temperature_deviation = calculate_temperature_deviation_for_correction(pressure_altitude, temperature)?;
+
+        Ok(Corrections::new(
+            pressure_altitude,
+            temperature_deviation,
+            slope,
+            grass_surface,
+            surface_condition,
+            &TakeoffOptions::default(),
+        ))
+    }
+
+    fn table_values(values: &Sorted<Vec<f64>>) -> Vec<f64> {
+        (0..values.len()).map(|index| values.gen(index)).collect()
+    }
+
+    fn apply_pressure_altitude_correction(takeoff_distance: f64, pressure_altitude: f64) -> f64 {
+        takeoff_distance * pressure_altitude_multiplier(pressure_altitude)
+    }
+
+    fn apply_temperature_correction(takeoff_distance: f64, temperature_deviation: f64) -> f64 {
+        takeoff_distance * temperature_multiplier(temperature_deviation)
+    }
+
+    #[test]
+    fn takeoff_distance_ordering() {
+        let short = TakeoffDistance::from((160.0, 350.0));
+        let long = TakeoffDistance::from((150.0, 380.0));
+        let longest = TakeoffDistance::from((155.0, 380.0));
+
+        let mut distances = [longest, short, long];
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(distances, [short, long, longest]);
+        assert_eq!(TakeoffDistance::limiting(&distances), Some(&longest));
+    }
+
+    #[test]
+    fn limiting_takeoff_distance_of_none() {
+        assert_eq!(TakeoffDistance::limiting(&[]), None);
+    }
+
+    #[test]
+    fn embedded_uls_table() {
+        let result = takeoff_distances_by_engine(Engine::Rotax912Uls);
+        assert_eq!(table_values(&result.mass), vec![472.5, 525.0, 540.0, 570.0, 600.0]);
+        assert_eq!(table_values(&result.takeoff_run), vec![100.0, 128.0, 136.0, 141.0, 153.0]);
+        assert_eq!(table_values(&result.to_50_feet), vec![225.0, 320.0, 338.0, 352.0, 375.0]);
+    }
+
+    #[test]
+    fn embedded_ul_table() {
+        let result = takeoff_distances_by_engine(Engine::Rotax912Ul);
+        assert_eq!(table_values(&result.mass), vec![472.5, 525.0, 540.0]);
+        assert_eq!(table_values(&result.takeoff_run), vec![106.0, 140.0, 147.0]);
+        assert_eq!(table_values(&result.to_50_feet), vec![265.0, 350.0, 367.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn takeoff_table_unsorted_masses() {
+        parse_takeoff_distances("mass,takeoff_run,to_50_feet\n500,100,200\n500,110,220\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 3 columns")]
+    fn takeoff_table_missing_column() {
+        parse_takeoff_distances("mass,takeoff_run,to_50_feet\n500,100\n");
+    }
+
     #[test]
     fn uls_472_weight_too_low() {
         let result = calculate_takeoff_distance(
@@ -270,6 +1396,37 @@ mod tests {
         assert_eq!("Mass 600.1 kg is above the maximum available data (600 kg)", result.unwrap_err().to_string());
     }
 
+    #[test]
+    fn uls_mass_boundaries_inclusive() {
+        for mass in [472.5, 600.0] {
+            let result = calculate_takeoff_distance(Engine::Rotax912Uls, mass, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous);
+            assert!(result.is_ok(), "Mass {mass} kg must be accepted");
+        }
+    }
+
+    #[test]
+    fn uls_mass_just_below_minimum() {
+        let result = calculate_takeoff_distance(Engine::Rotax912Uls, 472.49999, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert!(matches!(result, Err(TakeoffCalculationError::MassTooLow { .. })));
+    }
+
+    #[test]
+    fn uls_mass_just_above_maximum() {
+        let result = calculate_takeoff_distance(Engine::Rotax912Uls, 600.00001, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert!(matches!(result, Err(TakeoffCalculationError::MassTooHigh { .. })));
+    }
+
+    #[test]
+    fn ul_mass_boundaries_inclusive() {
+        for mass in [472.5, 540.0] {
+            let result = calculate_takeoff_distance(Engine::Rotax912Ul, mass, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous);
+            assert!(result.is_ok(), "Mass {mass} kg must be accepted");
+        }
+
+        let result = calculate_takeoff_distance(Engine::Rotax912Ul, 540.00001, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert!(matches!(result, Err(TakeoffCalculationError::MassTooHigh { .. })));
+    }
+
     #[test]
     fn uls_472_pressure_altitude_too_low() {
         let result = calculate_takeoff_distance(
@@ -296,7 +1453,7 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (100.0, 225.0));
+        assert_takeoff_distance(result, (100.0, 225.0));
     }
 
     #[test]
@@ -310,7 +1467,7 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (100.0, 225.0));
+        assert_takeoff_distance(result, (100.0, 225.0));
     }
 
     #[test]
@@ -324,7 +1481,60 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Slush,
         );
-        assert_eq!(result.unwrap(), (130.0, 292.5));
+        assert_takeoff_distance(result, (130.0, 292.5));
+    }
+
+    #[test]
+    fn uls_600_slush_5_mm() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Contaminated { kind: ContaminantKind::Slush, depth_mm: 5.0 },
+        );
+        assert_takeoff_distance(result, (175.95, 431.25));
+    }
+
+    #[test]
+    fn uls_600_slush_20_mm() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Contaminated { kind: ContaminantKind::Slush, depth_mm: 20.0 },
+        );
+        assert_takeoff_distance(result, (244.8, 600.0));
+    }
+
+    #[test]
+    fn contamination_equivalent_depth_of_flat_factors() {
+        assert!(approx_eq(contamination_multiplier(ContaminantKind::Slush, 10.0), 1.3, 1e-9));
+        assert!(approx_eq(contamination_multiplier(ContaminantKind::Snow, 10.0), 1.5, 1e-9));
+        assert!(approx_eq(contamination_multiplier(ContaminantKind::PowderSnow, 10.0), 1.25, 1e-9));
+    }
+
+    #[test]
+    fn contamination_depth_is_limited() {
+        let result = contamination_multiplier(ContaminantKind::Slush, 100.0);
+        assert_eq!(result, contamination_multiplier(ContaminantKind::Slush, 30.0));
+    }
+
+    #[test]
+    fn uls_600_invalid_contamination_depth() {
+        for depth_mm in [-5.0, f64::NAN, f64::INFINITY] {
+            let surface_condition = SurfaceCondition::Contaminated { kind: ContaminantKind::Slush, depth_mm };
+            let result = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, None, surface_condition);
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidContaminationDepth { .. })), "{depth_mm}: {result:?}");
+
+            let result = calculate_takeoff_distance_by_isa_deviation(Engine::Rotax912Uls, 600.0, 0.0, 0.0, 0.0, None, surface_condition);
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidContaminationDepth { .. })), "{depth_mm}: {result:?}");
+        }
     }
 
     #[test]
@@ -338,168 +1548,451 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Snow,
         );
-        assert_eq!(result.unwrap(), (150.0, 337.5));
+        assert_takeoff_distance(result, (150.0, 337.5));
     }
 
     #[test]
     fn uls_472_powder_snow() {
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            472.5,
+            472.5,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::PowderSnow,
+        );
+        assert_takeoff_distance(result, (125.0, 281.25));
+    }
+
+    #[test]
+    fn ul_472() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Ul,
+            472.5,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (106.0, 265.0));
+    }
+
+    #[test]
+    fn uls_472_temp() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            472.5,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (100.0, 225.0));
+    }
+
+    #[test]
+    fn uls_472_pressure() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            472.5,
+            3000.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (147.26, 331.33));
+    }
+
+    #[test]
+    fn uls_472_pressure2() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            472.5,
+            3200.5,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (167.6, 377.1));
+    }
+
+    #[test]
+    fn uls_525() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            525.0,
+            0.0,
+            15.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (128.0, 320.0));
+    }
+
+    #[test]
+    fn uls_525_temp() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            525.0,
+            0.0,
+            3.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (112.64, 281.6));
+    }
+
+    #[test]
+    fn uls_525_isa_deviation() {
+        let result = calculate_takeoff_distance_by_isa_deviation(
+            Engine::Rotax912Uls,
+            525.0,
+            0.0,
+            -12.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (112.64, 281.6));
+    }
+
+    #[test]
+    fn uls_525_isa_deviation_matches_temperature() {
+        let by_temperature = calculate_takeoff_distance(Engine::Rotax912Uls, 525.0, 2000.0, 25.0, 0.0, None, SurfaceCondition::Inconspicuous);
+        let isa_deviation = calculate_temperature_deviation(feet_to_meter(2000.0), 25.0).unwrap();
+        let by_isa_deviation = calculate_takeoff_distance_by_isa_deviation(Engine::Rotax912Uls, 525.0, 2000.0, isa_deviation, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert_eq!(by_isa_deviation.unwrap(), by_temperature.unwrap());
+    }
+
+    #[test]
+    fn uls_525_isa_deviation_pressure_altitude_too_high() {
+        let result = calculate_takeoff_distance_by_isa_deviation(
+            Engine::Rotax912Uls,
+            525.0,
+            262467.2,
+            0.0,
+            0.0,
+            None,
+            SurfaceCondition::Inconspicuous,
+        );
+        assert!(matches!(result, Err(TakeoffCalculationError::InvalidPressureAltitude { .. })));
+    }
+
+    #[test]
+    fn invalid_pressure_altitude_source_chain() {
+        let error = calculate_takeoff_distance_by_isa_deviation(
+            Engine::Rotax912Uls,
+            525.0,
+            262467.2,
+            0.0,
+            0.0,
+            None,
+            SurfaceCondition::Inconspicuous,
+        )
+        .unwrap_err();
+
+        let source = std::error::Error::source(&error).expect("pressure altitude error as source");
+        let root_cause = source.downcast_ref::<UndefinedPressureAltitudeError>();
+        assert!(matches!(root_cause, Some(UndefinedPressureAltitudeError::AboveMaximum { .. })));
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn uls_525_slope() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            525.0,
             0.0,
             15.0,
-            0.0,
+            -2.2,
             Some(GrassSurface::default()),
-            SurfaceCondition::PowderSnow,
+            SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (125.0, 281.25));
+        assert_takeoff_distance(result, (99.84, 249.6));
     }
 
     #[test]
-    fn ul_472() {
-        let result = calculate_takeoff_distance(
-            Engine::Rotax912Ul,
-            472.5,
+    fn uls_525_slope_degrees() {
+        let result = calculate_takeoff_distance_slope_deg(
+            Engine::Rotax912Uls,
+            525.0,
             0.0,
             15.0,
-            0.0,
+            gradient_to_angle(-2.2),
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (106.0, 265.0));
+        assert_takeoff_distance(result, (99.84, 249.6));
     }
 
     #[test]
-    fn uls_472_temp() {
+    fn slope_degrees_two_percent() {
+        let result = round(angle_to_gradient(1.15), 2);
+        assert_eq!(result, 2.01);
+    }
+
+    #[test]
+    fn uls_550() {
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            472.5,
+            550.0,
             0.0,
             15.0,
             0.0,
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (100.0, 225.0));
+        assert_takeoff_distance(result, (137.67, 342.67));
     }
 
     #[test]
-    fn uls_472_pressure() {
+    fn uls_600() {
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            472.5,
-            3000.0,
+            600.0,
+            0.0,
             15.0,
             0.0,
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (147.26, 331.33));
+        assert_takeoff_distance(result, (153.0, 375.0));
     }
 
     #[test]
-    fn uls_472_pressure2() {
-        let result = calculate_takeoff_distance(
+    fn uls_600_combined_by_conditions() {
+        let conditions = PerformanceConditions::from((
             Engine::Rotax912Uls,
-            472.5,
-            3200.5,
-            15.0,
-            0.0,
-            Some(GrassSurface::default()),
+            600.0,
+            2000.5,
+            -2.0,
+            3.0,
+            Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true, grass_height_cm: None }),
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (167.6, 377.1));
+        ));
+        let result = calculate_takeoff_distance_by_conditions(&conditions);
+        assert_takeoff_distance(result, (485.6, 1190.2));
     }
 
     #[test]
-    fn uls_525() {
-        let result = calculate_takeoff_distance(
+    fn uls_600_dual() {
+        let result = calculate_takeoff_distance_dual(
             Engine::Rotax912Uls,
-            525.0,
+            600.0,
             0.0,
             15.0,
             0.0,
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (128.0, 320.0));
+        )
+        .unwrap();
+        assert_eq!((result.ground_roll_m, result.over_50ft_m), (153.0, 375.0));
+        assert_eq!(result.ground_roll_ft, round(meter_to_feet(result.ground_roll_m), 2));
+        assert_eq!(result.over_50ft_ft, round(meter_to_feet(result.over_50ft_m), 2));
+        assert_eq!((result.ground_roll_ft, result.over_50ft_ft), (501.97, 1230.31));
     }
 
     #[test]
-    fn uls_525_temp() {
+    fn uls_600_max_temperature_for_short_strip() {
+        let result = max_temperature_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 400.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous).unwrap();
+        assert!(result < MAX_TEMP);
+        assert_eq!(result, 21.66);
+
+        let distance = |temperature| calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, temperature, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous).unwrap().1;
+        assert!(distance(result) <= 400.0);
+        assert!(distance(result + TEMPERATURE_SEARCH_TOLERANCE) > 400.0);
+    }
+
+    #[test]
+    fn uls_600_max_temperature_above_sea_level() {
+        let result = max_temperature_for_runway(Engine::Rotax912Uls, 600.0, 3000.0, 550.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous).unwrap();
+        assert_eq!(result, 14.57);
+
+        let distance = |temperature| calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 3000.0, temperature, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous).unwrap().1;
+        assert!(distance(result) <= 550.0, "{result} °C does not fit");
+        assert!(distance(result + TEMPERATURE_SEARCH_TOLERANCE) > 550.0, "{result} °C is not the limit");
+    }
+
+    #[test]
+    fn uls_600_max_temperature_for_long_runway() {
+        let result = max_temperature_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 2000.0, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert_eq!(result.unwrap(), MAX_TEMP);
+    }
+
+    #[test]
+    fn uls_600_max_slope_for_runway() {
+        let result = max_slope_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 400.0, None, SurfaceCondition::Inconspicuous).unwrap();
+        assert!(result < MAX_SLOPE);
+        assert_eq!(result, 2.8);
+
+        let distance = |slope| calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, slope, None, SurfaceCondition::Inconspicuous).unwrap().1;
+        assert!(distance(result) <= 400.0);
+        assert!(distance(result + 0.1) > 400.0);
+    }
+
+    #[test]
+    fn uls_600_max_slope_for_runway_at_steepest_downhill_slope() {
+        let result = max_slope_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 187.5, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous);
+        assert_eq!(result.unwrap(), -MAX_DOWNHILL_SLOPE);
+    }
+
+    #[test]
+    fn uls_600_max_slope_for_too_short_runway() {
+        let result = max_slope_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 150.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous);
+        assert!(matches!(
+            result,
+            Err(TakeoffCalculationError::RunwayTooShort { available, required }) if available == 150.0 && required == 187.5
+        ));
+    }
+
+    #[test]
+    fn uls_600_max_slope_for_long_runway() {
+        let result = max_slope_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 2000.0, None, SurfaceCondition::Inconspicuous);
+        assert_eq!(result.unwrap(), MAX_SLOPE);
+    }
+
+    #[test]
+    fn uls_600_runway_too_short() {
+        let result = max_temperature_for_runway(Engine::Rotax912Uls, 600.0, 0.0, 200.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous);
+        assert!(matches!(result, Err(TakeoffCalculationError::RunwayTooShort { .. })));
+    }
+
+    #[test]
+    fn uls_600_wet() {
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            525.0,
+            600.0,
             0.0,
-            3.0,
+            15.0,
             0.0,
-            Some(GrassSurface::default()),
+            Some(GrassSurface { wet: true, ..GrassSurface::default() }),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (112.64, 281.6));
+        assert_takeoff_distance(result, (168.3, 412.5));
     }
 
     #[test]
-    fn uls_525_slope() {
-        let result = calculate_takeoff_distance(
+    fn uls_600_wet_and_soft() {
+        let result =
+            calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: false, high_grass: false, grass_height_cm: None }), SurfaceCondition::Inconspicuous);
+        assert_takeoff_distance(result, (252.45, 618.75));
+    }
+
+    #[test]
+    fn uls_600_grass_height_30_cm() {
+        let by_flag = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            525.0,
+            600.0,
             0.0,
             15.0,
-            -2.2,
-            Some(GrassSurface::default()),
+            0.0,
+            Some(GrassSurface { high_grass: true, ..GrassSurface::default() }),
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (99.84, 249.6));
-    }
-
-    #[test]
-    fn uls_550() {
+        )
+        .unwrap();
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
-            550.0,
+            600.0,
             0.0,
             15.0,
             0.0,
-            Some(GrassSurface::default()),
+            Some(GrassSurface { grass_height_cm: Some(30.0), ..GrassSurface::default() }),
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (137.67, 342.67));
+        )
+        .unwrap();
+        assert_eq!(result, (214.2, 525.0));
+        assert!(result.0 > by_flag.0 && result.1 > by_flag.1);
     }
 
     #[test]
-    fn uls_600() {
-        let result = calculate_takeoff_distance(
+    fn uls_600_invalid_grass_height() {
+        for grass_height_cm in [-5.0, f64::NAN, f64::INFINITY] {
+            let grass_surface = Some(GrassSurface { grass_height_cm: Some(grass_height_cm), ..GrassSurface::default() });
+            let result = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, grass_surface, SurfaceCondition::Inconspicuous);
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidGrassHeight { .. })), "{grass_height_cm}: {result:?}");
+
+            let result = calculate_takeoff_distance_by_isa_deviation(Engine::Rotax912Uls, 600.0, 0.0, 0.0, 0.0, grass_surface, SurfaceCondition::Inconspicuous);
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidGrassHeight { .. })), "{grass_height_cm}: {result:?}");
+        }
+    }
+
+    #[test]
+    fn uls_600_half_grass_between_paved_and_grass() {
+        let grass = GrassSurface { wet: true, soft_ground: true, ..GrassSurface::default() };
+        let paved = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+        let full_grass = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(grass), SurfaceCondition::Inconspicuous).unwrap();
+
+        let result = calculate_takeoff_distance_mixed_surface(
             Engine::Rotax912Uls,
             600.0,
             0.0,
             15.0,
             0.0,
-            Some(GrassSurface::default()),
+            MixedSurface { grass_fraction: 0.5, grass },
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (153.0, 375.0));
+        )
+        .unwrap();
+        assert!(paved.0 < result.0 && result.0 < full_grass.0, "{result:?}");
+        assert!(paved.1 < result.1 && result.1 < full_grass.1, "{result:?}");
     }
 
     #[test]
-    fn uls_600_wet() {
-        let result = calculate_takeoff_distance(
+    fn uls_600_mixed_surface_limits() {
+        let grass = GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true, grass_height_cm: None };
+        let mixed = |grass_fraction| {
+            calculate_takeoff_distance_mixed_surface(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, MixedSurface { grass_fraction, grass }, SurfaceCondition::Inconspicuous).unwrap()
+        };
+
+        let result = mixed(0.0);
+        assert_eq!(result, calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous).unwrap());
+
+        let result = mixed(1.0);
+        assert_eq!(result, calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(grass), SurfaceCondition::Inconspicuous).unwrap());
+        assert_eq!(result, (333.23, 816.75));
+    }
+
+    #[test]
+    fn uls_600_mixed_surface_fraction_out_of_range() {
+        let mixed = |grass_fraction| {
+            calculate_takeoff_distance_mixed_surface(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, MixedSurface { grass_fraction, grass: GrassSurface::default() }, SurfaceCondition::Inconspicuous)
+        };
+
+        assert!(matches!(mixed(1.5), Err(TakeoffCalculationError::GrassFractionOutOfRange { grass_fraction }) if grass_fraction == 1.5));
+        assert!(matches!(mixed(-0.1), Err(TakeoffCalculationError::GrassFractionOutOfRange { .. })));
+    }
+
+    #[test]
+    fn uls_600_half_grass_split_at_transition() {
+        let grass = GrassSurface { wet: true, ..GrassSurface::default() };
+        let result = calculate_takeoff_distance_mixed_surface(
             Engine::Rotax912Uls,
             600.0,
             0.0,
             15.0,
             0.0,
-            Some(GrassSurface { wet: true, ..GrassSurface::default() }),
+            MixedSurface { grass_fraction: 0.5, grass },
             SurfaceCondition::Inconspicuous,
-        );
-        assert_eq!(result.unwrap(), (168.3, 412.5));
+        )
+        .unwrap();
+
+        // 127.5 m paved run and a grass multiplier of 1.2 * 1.1, with half the roll on grass
+        let multiplier = 1.0 / (1.0 - 0.5 * (1.0 - 1.0 / (1.2 * 1.1)));
+        assert!(approx_eq(result.0, 127.5 * multiplier, EPSILON), "{result:?}");
+        assert!(approx_eq(result.1, 312.5 * multiplier, EPSILON), "{result:?}");
     }
 
     #[test]
-    fn uls_600_wet_and_soft() {
-        let result =
-            calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: false, high_grass: false }), SurfaceCondition::Inconspicuous);
-        assert_eq!(result.unwrap(), (252.45, 618.75));
+    fn grass_height_multiplier_bounds() {
+        assert_eq!(grass_height_multiplier(5.0), 1.0);
+        assert!(approx_eq(grass_height_multiplier(20.0), 1.2, 1e-9));
+        assert_eq!(grass_height_multiplier(100.0), grass_height_multiplier(40.0));
     }
 
     #[test]
@@ -510,10 +2003,32 @@ mod tests {
             2000.5,
             -2.0,
             3.0,
-            Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true }),
+            Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true, grass_height_cm: None }),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (485.6, 1190.2));
+        assert_takeoff_distance(result, (485.6, 1190.2));
+    }
+
+    #[test]
+    fn uls_600_combined_dominant_correction() {
+        let conditions = PerformanceConditions::from((
+            Engine::Rotax912Uls,
+            600.0,
+            2000.5,
+            -2.0,
+            3.0,
+            Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true, grass_height_cm: None }),
+            SurfaceCondition::Inconspicuous,
+        ));
+        let breakdown = takeoff_breakdown(&conditions).unwrap();
+        assert!(approx_eq(breakdown.grass, 1.2 * 1.1 * 1.5 * 1.1 * 1.2, 1e-9), "{}", breakdown.grass);
+        assert_eq!(dominant_correction(&breakdown), "grass");
+    }
+
+    #[test]
+    fn dominant_correction_tie_takes_first() {
+        let breakdown = TakeoffBreakdown { pressure_altitude: 1.0, temperature: 1.0, slope: 1.0, grass: 1.0, surface: 1.0 };
+        assert_eq!(dominant_correction(&breakdown), "altitude");
     }
 
     #[test]
@@ -527,7 +2042,7 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (11773.24, 28855.99));
+        assert_takeoff_distance(result, (11773.24, 28855.99));
     }
 
     #[test]
@@ -544,6 +2059,57 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn uls_600_max_pressure_altitude_capped() {
+        let conditions = PerformanceConditions::from((Engine::Rotax912Uls, 600.0, 262467.1, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous));
+        let options = TakeoffOptions { max_total_factor: Some(3.0), ..TakeoffOptions::default() };
+        let result = calculate_takeoff_report(&conditions, options);
+        assert_eq!(result.unwrap(), TakeoffReport { takeoff_run: 382.5, to_50_feet: 937.5, capped: true });
+    }
+
+    #[test]
+    fn uls_600_below_max_total_factor() {
+        let conditions = PerformanceConditions::from((Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous));
+        let options = TakeoffOptions { max_total_factor: Some(3.0), ..TakeoffOptions::default() };
+        let result = calculate_takeoff_report(&conditions, options);
+        assert_eq!(result.unwrap(), TakeoffReport { takeoff_run: 153.0, to_50_feet: 375.0, capped: false });
+    }
+
+    #[test]
+    fn uls_600_invalid_max_total_factor() {
+        let conditions = PerformanceConditions::from((Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous));
+        for max_total_factor in [0.5, -1.0, 0.0, f64::NAN, f64::INFINITY] {
+            let options = TakeoffOptions { max_total_factor: Some(max_total_factor), ..TakeoffOptions::default() };
+            let result = calculate_takeoff_report(&conditions, options);
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidMaxTotalFactor { .. })), "{max_total_factor}: {result:?}");
+        }
+    }
+
+    #[test]
+    fn uls_600_max_total_factor_without_report() {
+        let options = TakeoffOptions { max_total_factor: Some(3.0), ..TakeoffOptions::default() };
+        let result = calculate_takeoff_distance_with_options(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, None, SurfaceCondition::Inconspicuous, options);
+        assert!(matches!(result, Err(TakeoffCalculationError::UnflaggedMaxTotalFactor)));
+    }
+
+    #[test]
+    fn uls_525_rounding_up() {
+        let nearest = calculate_takeoff_distance(Engine::Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+        let options = TakeoffOptions { rounding: RoundingMode::Up, ..TakeoffOptions::default() };
+        let result = calculate_takeoff_distance_with_options(Engine::Rotax912Uls, 525.0, 100.0, 21.3, 0.0, None, SurfaceCondition::Inconspicuous, options).unwrap();
+        assert_eq!(result, (nearest.0.ceil(), nearest.1.ceil()));
+    }
+
+    #[test]
+    fn uls_600_rolling_start() {
+        let grass_surface = Some(GrassSurface::default());
+        let static_start = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, grass_surface, SurfaceCondition::Inconspicuous).unwrap();
+        let options = TakeoffOptions { start_technique: StartTechnique::Rolling, ..TakeoffOptions::default() };
+        let result = calculate_takeoff_distance_with_options(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, grass_surface, SurfaceCondition::Inconspicuous, options);
+        assert_takeoff_distance(result, (160.65, 393.75));
+        assert!(static_start.0 < 160.65);
+    }
+
     #[test]
     fn uls_600_min_pressure_altitude() {
         let result = calculate_takeoff_distance(
@@ -555,7 +2121,7 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (143.05, 350.63));
+        assert_takeoff_distance(result, (143.05, 350.63));
     }
 
     #[test]
@@ -573,17 +2139,17 @@ mod tests {
     }
 
     #[test]
-    fn uls_600_min_temperature() {
+    fn uls_600_min_isa_deviation() {
         let result = calculate_takeoff_distance(
             Engine::Rotax912Uls,
             600.0,
             0.0,
-            -90.0,
+            -45.0,
             0.0,
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (130.05, 318.75));
+        assert_takeoff_distance(result, (130.05, 318.75));
     }
 
     #[test]
@@ -600,6 +2166,41 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn uls_600_below_min_isa_deviation() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            0.0,
+            -45.1,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert!(matches!(result, Err(TakeoffCalculationError::IsaDeviationTooLow { .. })));
+        assert_eq!("ISA deviation -60.1 °C is below the minimum sensible deviation (-60 °C)", result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn uls_600_above_max_isa_deviation() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            10000.0,
+            56.0,
+            0.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert!(matches!(result, Err(TakeoffCalculationError::IsaDeviationTooHigh { .. })));
+    }
+
+    #[test]
+    fn uls_600_by_isa_deviation_above_max() {
+        let result = calculate_takeoff_distance_by_isa_deviation(Engine::Rotax912Uls, 600.0, 0.0, 60.1, 0.0, None, SurfaceCondition::Inconspicuous);
+        assert!(matches!(result, Err(TakeoffCalculationError::IsaDeviationTooHigh { .. })));
+    }
+
     #[test]
     fn uls_600_max_temperature() {
         let result = calculate_takeoff_distance(
@@ -611,7 +2212,7 @@ mod tests {
             Some(GrassSurface::default()),
             SurfaceCondition::Inconspicuous,
         );
-        assert_eq!(result.unwrap(), (237.15, 581.25));
+        assert_takeoff_distance(result, (237.15, 581.25));
     }
 
     #[test]
@@ -656,12 +2257,171 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn uls_600_max_negative_slope() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            0.0,
+            15.0,
+            -5.0,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_takeoff_distance(result, (76.5, 187.5));
+    }
+
+    #[test]
+    fn uls_600_below_max_downhill_slope() {
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            600.0,
+            0.0,
+            15.0,
+            -5.1,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert!(matches!(result, Err(TakeoffCalculationError::SlopeTooSteep { max, .. }) if max == -MAX_DOWNHILL_SLOPE));
+    }
+
+    #[test]
+    fn uls_525_engine_derate_disabled() {
+        let result = calculate_takeoff_distance_with_options(
+            Engine::Rotax912Uls,
+            525.0,
+            5000.0,
+            5.1,
+            0.0,
+            None,
+            SurfaceCondition::Inconspicuous,
+            TakeoffOptions::default(),
+        );
+        assert_eq!(result.unwrap(), calculate_takeoff_distance(Engine::Rotax912Uls, 525.0, 5000.0, 5.1, 0.0, None, SurfaceCondition::Inconspicuous).unwrap());
+    }
+
+    #[test]
+    fn uls_525_engine_derate_5000_ft() {
+        let chart = calculate_takeoff_distance(Engine::Rotax912Uls, 525.0, 5000.0, 5.1, 0.0, None, SurfaceCondition::Inconspicuous).unwrap();
+        let derated = calculate_takeoff_distance_with_options(
+            Engine::Rotax912Uls,
+            525.0,
+            5000.0,
+            5.1,
+            0.0,
+            None,
+            SurfaceCondition::Inconspicuous,
+            TakeoffOptions { engine_derate: Some(0.03), ..TakeoffOptions::default() },
+        ).unwrap();
+        assert_eq!(chart, (202.69, 506.72));
+        assert_eq!(derated, (233.1, 582.74));
+    }
+
+    #[test]
+    fn uls_525_invalid_engine_derate() {
+        for engine_derate in [-0.5, f64::NAN, f64::INFINITY] {
+            let result = calculate_takeoff_distance_with_options(
+                Engine::Rotax912Uls,
+                525.0,
+                5000.0,
+                5.1,
+                0.0,
+                None,
+                SurfaceCondition::Inconspicuous,
+                TakeoffOptions { engine_derate: Some(engine_derate), ..TakeoffOptions::default() },
+            );
+            assert!(matches!(result, Err(TakeoffCalculationError::InvalidEngineDerate { .. })), "{engine_derate}: {result:?}");
+        }
+    }
+
+    #[test]
+    fn engine_derate_below_sea_level() {
+        let result = engine_derate_multiplier(-500.0, 0.0, 0.03);
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn single_pass_corrections_snapshot() {
+        let cases = [
+            (Engine::Rotax912Uls, 472.5, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Slush, (130.0, 292.5)),
+            (Engine::Rotax912Uls, 472.5, 3200.5, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous, (167.6, 377.1)),
+            (Engine::Rotax912Uls, 525.0, 0.0, 15.0, -2.2, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous, (99.84, 249.6)),
+            (Engine::Rotax912Uls, 550.0, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous, (137.67, 342.67)),
+            (Engine::Rotax912Uls, 600.0, 2000.5, -2.0, 3.0, Some(GrassSurface { wet: true, soft_ground: true, damaged_turf: true, high_grass: true, grass_height_cm: None }), SurfaceCondition::Inconspicuous, (485.6, 1190.2)),
+            (Engine::Rotax912Uls, 600.0, 262467.1, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous, (11773.24, 28855.99)),
+            (Engine::Rotax912Uls, 520.0, 364.0, 21.0, 0.0, None, SurfaceCondition::Inconspicuous, (115.52, 286.61)),
+            (Engine::Rotax912Ul, 472.5, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous, (106.0, 265.0)),
+        ];
+
+        for (engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition, expected) in cases {
+            let result = calculate_takeoff_distance(engine, mass, pressure_altitude, temperature, slope, grass_surface, surface_condition);
+            assert_takeoff_distance(result, expected);
+        }
+    }
+
     #[test]
     fn apply_corrections_fsm75_3_example1() {
         let result = apply_corrections(316.0, 600.0, -3.0, 0.0, None, SurfaceCondition::Snow);
         assert_eq!(result.unwrap(), 433.05); // 444
     }
 
+    #[test]
+    fn apply_corrections_fsm75_3_example1_raw() {
+        let raw = corrections(600.0, -3.0, 0.0, None, SurfaceCondition::Snow).unwrap().apply_raw(316.0);
+        let rounded = apply_corrections(316.0, 600.0, -3.0, 0.0, None, SurfaceCondition::Snow).unwrap();
+        assert!(approx_eq(raw, rounded, 0.005), "{raw}");
+        assert_ne!(raw, rounded);
+    }
+
+    #[test]
+    fn uls_600_raw() {
+        let (takeoff_run, to_50_feet) = calculate_takeoff_distance_raw(
+            Engine::Rotax912Uls,
+            560.0,
+            1234.0,
+            17.3,
+            1.5,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        )
+        .unwrap();
+        let result = calculate_takeoff_distance(
+            Engine::Rotax912Uls,
+            560.0,
+            1234.0,
+            17.3,
+            1.5,
+            Some(GrassSurface::default()),
+            SurfaceCondition::Inconspicuous,
+        );
+        assert_eq!(result.unwrap(), (round(takeoff_run, 2), round(to_50_feet, 2)));
+    }
+
+    #[test]
+    fn takeoff_distance_approx_matches_tolerance() {
+        let expected = TakeoffDistance::from((168.3, 412.5));
+        assert!(TakeoffDistance::from((168.301, 412.499)).approx_matches(&expected, EPSILON));
+        assert!(!TakeoffDistance::from((169.3, 412.5)).approx_matches(&expected, EPSILON));
+        assert!(!TakeoffDistance::from((168.3, 413.5)).approx_matches(&expected, EPSILON));
+    }
+
+    #[test]
+    fn takeoff_distance_error_fsm75_3_examples() {
+        let computed = TakeoffDistance::from((
+            apply_corrections(316.0, 600.0, -3.0, 0.0, None, SurfaceCondition::Snow).unwrap(),
+            apply_corrections(465.0, 2000.0, 1.0, 0.0, Some(GrassSurface { wet: true, ..GrassSurface::default() }), SurfaceCondition::Slush).unwrap(),
+        ));
+        let result = takeoff_distance_error(&computed, &TakeoffDistance::from((444.0, 904.0)));
+        assert_eq!(result, (-2.47, 0.05));
+
+        let computed = TakeoffDistance::from((
+            apply_corrections(465.0, 1150.0, 35.0, 0.0, None, SurfaceCondition::Inconspicuous).unwrap(),
+            apply_corrections(465.0, 600.0, 28.0, 0.0, Some(GrassSurface { wet: true, ..GrassSurface::default() }), SurfaceCondition::Slush).unwrap(),
+        ));
+        let result = takeoff_distance_error(&computed, &TakeoffDistance::from((653.0, 1002.0)));
+        assert_eq!(result, (0.09, -3.61));
+    }
+
     #[test]
     fn apply_corrections_fsm75_3_example2() {
         let result = apply_corrections(465.0, 2000.0, 1.0, 0.0, Some(GrassSurface {
@@ -669,6 +2429,7 @@ mod tests {
             soft_ground: false,
             damaged_turf: false,
             high_grass: false,
+            grass_height_cm: None,
         }), SurfaceCondition::Slush);
         assert_eq!(result.unwrap(), 904.46); // 904
     }
@@ -686,10 +2447,42 @@ mod tests {
             soft_ground: false,
             damaged_turf: false,
             high_grass: false,
+            grass_height_cm: None,
         }), SurfaceCondition::Slush);
         assert_eq!(result.unwrap(), 965.84); // 1002
     }
 
+    #[test]
+    fn environmental_multipliers_uls_472_pressure() {
+        let (altitude, temperature) = environmental_multipliers(3000.0, 15.0).unwrap();
+        assert!(approx_eq(altitude, 1.39, 1e-9), "{altitude}");
+        assert!(approx_eq(temperature, 1.0594, 1e-9), "{temperature}");
+    }
+
+    #[test]
+    fn environmental_multipliers_reproduce_uls_472_pressure() {
+        let (altitude, temperature) = environmental_multipliers(3000.0, 15.0).unwrap();
+        assert_eq!(round(100.0 * altitude * temperature, 2), 147.26);
+    }
+
+    #[test]
+    fn interpolation_detail_uls_555() {
+        let result = interpolation_detail(Engine::Rotax912Uls, 555.0);
+        assert_eq!(result.unwrap(), (540.0, 570.0, 0.5));
+    }
+
+    #[test]
+    fn interpolation_detail_mass_too_high() {
+        let result = interpolation_detail(Engine::Rotax912Ul, 555.0);
+        assert!(matches!(result, Err(TakeoffCalculationError::MassTooHigh { .. })));
+    }
+
+    #[test]
+    fn environmental_multipliers_temperature_too_high() {
+        let result = environmental_multipliers(0.0, 70.1);
+        assert!(matches!(result, Err(TakeoffCalculationError::TemperatureTooHigh { .. })));
+    }
+
     #[test]
     fn pressure_altitude() {
         let result = apply_pressure_altitude_correction(465.0, 2000.0);
@@ -744,4 +2537,43 @@ mod tests {
         let result = apply_temperature_correction(120.0, 10.0);
         assert_eq!(result, 132.0);
     }
+
+    proptest! {
+        #[test]
+        fn takeoff_distance_is_finite_and_positive(
+            uls in any::<bool>(),
+            mass_fraction in 0.0..=1.0_f64,
+            pressure_altitude in -3280.8..=262467.1_f64,
+            isa_deviation in MIN_ISA_DEVIATION..=MAX_ISA_DEVIATION,
+            slope in -MAX_DOWNHILL_SLOPE..=MAX_SLOPE,
+            grass in proptest::option::of((any::<bool>(), any::<bool>(), any::<bool>(), any::<bool>())),
+            surface_condition in prop_oneof![
+                Just(SurfaceCondition::Inconspicuous),
+                Just(SurfaceCondition::Slush),
+                Just(SurfaceCondition::Snow),
+                Just(SurfaceCondition::PowderSnow),
+                (0.0..=50.0_f64).prop_map(|depth_mm| SurfaceCondition::Contaminated { kind: ContaminantKind::Slush, depth_mm }),
+            ],
+        ) {
+            let engine = if uls { Engine::Rotax912Uls } else { Engine::Rotax912Ul };
+            let table = takeoff_distances_by_engine(engine);
+            let min = table.mass.first().unwrap();
+            let max = table.mass.last().unwrap();
+            let grass_surface = grass.map(|(wet, soft_ground, damaged_turf, high_grass)| GrassSurface { wet, soft_ground, damaged_turf, high_grass, grass_height_cm: None });
+            let temperature = (icao_temperature(feet_to_meter(pressure_altitude)).unwrap() + isa_deviation).clamp(MIN_TEMP, MAX_TEMP);
+
+            let (takeoff_run, to_50_feet) = calculate_takeoff_distance(
+                engine,
+                min + (max - min) * mass_fraction,
+                pressure_altitude,
+                temperature,
+                slope,
+                grass_surface,
+                surface_condition,
+            ).unwrap();
+
+            prop_assert!(takeoff_run.is_finite() && takeoff_run > 0.0, "Invalid takeoff run {}", takeoff_run);
+            prop_assert!(to_50_feet.is_finite() && to_50_feet > 0.0, "Invalid distance to 50 ft {}", to_50_feet);
+        }
+    }
 }
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use crate::navigation::leg_time;
+use crate::utils::{assert_finite, round};
+
+/// A leg of a flight plan
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlannedLeg {
+    pub ground_speed: f64,
+    pub distance: f64,
+}
+
+/// Fuel state after flying a leg
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegFuelState {
+    pub fuel_burned: f64,
+    pub fuel_remaining: f64,
+    pub exhausted: bool,
+}
+
+/// # Calculate Fuel Plan
+///
+/// ## Arguments
+///
+/// * `legs`: Legs of the flight, ground speed in the unit of the distance per hour
+/// * `starting_fuel`: Usable fuel at the start of the first leg in any unit
+/// * `burn_rate`: Fuel burn per hour in the same unit as the starting fuel
+///
+/// returns: Vec<LegFuelState> Fuel burned and remaining per leg, flagged as exhausted once the remaining fuel is negative
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fuel::*;
+///
+/// let legs = [PlannedLeg { ground_speed: 100.0, distance: 50.0 }, PlannedLeg { ground_speed: 90.0, distance: 30.0 }];
+/// let plan = fuel_plan(&legs, 60.0, 18.0);
+/// ```
+pub fn fuel_plan(legs: &[PlannedLeg], starting_fuel: f64, burn_rate: f64) -> Vec<LegFuelState> {
+    assert_finite(starting_fuel, "starting_fuel");
+    assert_finite(burn_rate, "burn_rate");
+
+    let mut fuel_remaining = starting_fuel;
+
+    legs.iter()
+        .map(|leg| {
+            let fuel_burned = round(burn_rate * leg_time(leg.distance, leg.ground_speed) / 60.0, 2);
+            fuel_remaining = round(fuel_remaining - fuel_burned, 2);
+
+            LegFuelState {
+                fuel_burned,
+                fuel_remaining,
+                exhausted: fuel_remaining < 0.0,
+            }
+        })
+        .collect()
+}
+
+/// # Calculate Endurance Duration
+///
+/// ## Arguments
+///
+/// * `fuel`: Usable fuel in any unit
+/// * `burn_rate`: Fuel burn per hour in the same unit as the fuel
+///
+/// returns: Option<Duration> Time until the fuel is exhausted, rounded to ms, or `None` if there is no sensible
+/// endurance, e.g. with negative fuel or a burn rate of zero or below
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fuel::*;
+///
+/// let endurance = endurance_duration(60.0, 18.0).unwrap();
+/// ```
+pub fn endurance_duration(fuel: f64, burn_rate: f64) -> Option<Duration> {
+    assert_finite(fuel, "fuel");
+    assert_finite(burn_rate, "burn_rate");
+
+    if fuel < 0.0 || burn_rate <= 0.0 {
+        return None;
+    }
+
+    Duration::try_from_secs_f64(round(fuel / burn_rate * 3600.0, 3)).ok()
+}
+
+/// # Calculate Cruise Fuel Flow
+///
+/// The fuel flow of a normally aspirated engine at a power setting relative to the power available at the density
+/// altitude, which decreases with the air density of the ICAO standard atmosphere.
+///
+/// ## Arguments
+///
+/// * `sea_level_flow`: Fuel flow at full power at sea level in any unit
+/// * `density_altitude_ft`: Density altitude in ft
+/// * `power_percent`: Power setting in percent of the power available at the density altitude
+///
+/// returns: f64 Fuel flow in the same unit as the sea level flow
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fuel::*;
+///
+/// let fuel_flow = cruise_fuel_flow(26.0, 4500.0, 75.0);
+/// ```
+pub fn cruise_fuel_flow(sea_level_flow: f64, density_altitude_ft: f64, power_percent: f64) -> f64 {
+    assert_finite(sea_level_flow, "sea_level_flow");
+    assert_finite(density_altitude_ft, "density_altitude_ft");
+    assert_finite(power_percent, "power_percent");
+
+    let density_ratio = (1.0 - 6.8756e-6 * density_altitude_ft).powf(4.2559);
+
+    round(sea_level_flow * power_percent / 100.0 * density_ratio, 2)
+}
+
+/// # Calculate Total Fuel Required
+///
+/// ## Arguments
+///
+/// * `legs_fuel`: Trip fuel for all legs to the destination in l
+/// * `alternate_fuel`: Fuel from the destination to the alternate in l
+/// * `reserve_minutes`: Final reserve in minutes, e.g. 30 or 45
+/// * `burn_rate`: Fuel burn per hour in l, used for the reserve
+/// * `taxi_fuel`: Fuel for starting and taxiing in l
+///
+/// returns: f64 Total fuel required in l
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::fuel::*;
+///
+/// let fuel = total_fuel_required(42.5, 12.0, 30.0, 18.0, 2.0);
+/// ```
+pub fn total_fuel_required(legs_fuel: f64, alternate_fuel: f64, reserve_minutes: f64, burn_rate: f64, taxi_fuel: f64) -> f64 {
+    assert_finite(legs_fuel, "legs_fuel");
+    assert_finite(alternate_fuel, "alternate_fuel");
+    assert_finite(reserve_minutes, "reserve_minutes");
+    assert_finite(burn_rate, "burn_rate");
+    assert_finite(taxi_fuel, "taxi_fuel");
+
+    let reserve_fuel = burn_rate * reserve_minutes / 60.0;
+
+    round(legs_fuel + alternate_fuel + reserve_fuel + taxi_fuel, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuel_plan_three_legs_last_exhausting() {
+        let legs = [
+            PlannedLeg { ground_speed: 100.0, distance: 50.0 },
+            PlannedLeg { ground_speed: 120.0, distance: 60.0 },
+            PlannedLeg { ground_speed: 100.0, distance: 100.0 },
+        ];
+        let result = fuel_plan(&legs, 30.0, 20.0);
+        assert_eq!(result, vec![
+            LegFuelState { fuel_burned: 10.0, fuel_remaining: 20.0, exhausted: false },
+            LegFuelState { fuel_burned: 10.0, fuel_remaining: 10.0, exhausted: false },
+            LegFuelState { fuel_burned: 20.0, fuel_remaining: -10.0, exhausted: true },
+        ]);
+    }
+
+    #[test]
+    fn fuel_plan_no_legs() {
+        let result = fuel_plan(&[], 30.0, 20.0);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn fuel_plan_exactly_empty() {
+        let result = fuel_plan(&[PlannedLeg { ground_speed: 100.0, distance: 150.0 }], 30.0, 20.0);
+        assert_eq!(result, vec![LegFuelState { fuel_burned: 30.0, fuel_remaining: 0.0, exhausted: false }]);
+    }
+
+    #[test]
+    fn endurance_duration_90_minutes() {
+        let result = endurance_duration(27.0, 18.0);
+        assert_eq!(result, Some(Duration::from_secs(5400)));
+    }
+
+    #[test]
+    fn endurance_duration_without_sensible_endurance() {
+        assert_eq!(endurance_duration(27.0, 0.0), None);
+        assert_eq!(endurance_duration(-27.0, 18.0), None);
+        assert_eq!(endurance_duration(-27.0, -18.0), None);
+    }
+
+    #[test]
+    fn cruise_fuel_flow_sea_level() {
+        let result = cruise_fuel_flow(30.0, 0.0, 75.0);
+        assert_eq!(result, 22.5);
+    }
+
+    #[test]
+    fn cruise_fuel_flow_decreases_with_density_altitude() {
+        let result = cruise_fuel_flow(30.0, 5000.0, 75.0);
+        assert!(result < cruise_fuel_flow(30.0, 0.0, 75.0));
+        assert_eq!(result, 19.39);
+    }
+
+    #[test]
+    fn total_fuel_required_all_components() {
+        let result = total_fuel_required(42.5, 12.0, 30.0, 18.0, 2.0);
+        assert_eq!(result, 65.5);
+    }
+
+    #[test]
+    fn total_fuel_required_each_component_included() {
+        assert_eq!(total_fuel_required(42.5, 0.0, 0.0, 18.0, 0.0), 42.5);
+        assert_eq!(total_fuel_required(0.0, 12.0, 0.0, 18.0, 0.0), 12.0);
+        assert_eq!(total_fuel_required(0.0, 0.0, 45.0, 18.0, 0.0), 13.5);
+        assert_eq!(total_fuel_required(0.0, 0.0, 0.0, 18.0, 2.0), 2.0);
+    }
+}
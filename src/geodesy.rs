@@ -0,0 +1,283 @@
+use crate::utils::{assert_finite, normalize_degree, to_degree, to_radian};
+
+const EARTH_RADIUS: f64 = 3440.065_f64; /* NM */
+
+/// # Calculate Great Circle Distance
+///
+/// Uses the haversine formula on a spherical earth, which stays accurate for very short legs as well as for antipodal
+/// points.
+///
+/// ## Arguments
+///
+/// * `lat1`: Latitude of the first point in decimal degrees, positive for north
+/// * `lon1`: Longitude of the first point in decimal degrees, positive for east
+/// * `lat2`: Latitude of the second point in decimal degrees, positive for north
+/// * `lon2`: Longitude of the second point in decimal degrees, positive for east
+///
+/// returns: f64 Distance in NM, unrounded to keep legs below a meter
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::geodesy::*;
+///
+/// let distance = great_circle_distance(50.0379, 8.5622, 49.9469, 8.9697);
+/// ```
+pub fn great_circle_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    assert_finite(lat1, "lat1");
+    assert_finite(lon1, "lon1");
+    assert_finite(lat2, "lat2");
+    assert_finite(lon2, "lon2");
+
+    let (lat1, lat2) = (to_radian(lat1), to_radian(lat2));
+    let delta_lat = lat2 - lat1;
+    let delta_lon = to_radian(lon2 - lon1);
+
+    let haversine = (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+
+    // Rounding errors may push the haversine slightly above 1 for antipodal points
+    let haversine = haversine.clamp(0.0, 1.0);
+
+    2.0 * EARTH_RADIUS * haversine.sqrt().atan2((1.0 - haversine).sqrt())
+}
+
+/// # Calculate Initial Great Circle Bearing
+///
+/// ## Arguments
+///
+/// * `lat1`: Latitude of the departure point in decimal degrees, positive for north
+/// * `lon1`: Longitude of the departure point in decimal degrees, positive for east
+/// * `lat2`: Latitude of the destination in decimal degrees, positive for north
+/// * `lon2`: Longitude of the destination in decimal degrees, positive for east
+///
+/// returns: f64 True bearing at the departure point in degrees from 0 to below 360
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::geodesy::*;
+///
+/// let course = initial_bearing(50.0379, 8.5622, 49.9469, 8.9697);
+/// ```
+pub fn initial_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    assert_finite(lat1, "lat1");
+    assert_finite(lon1, "lon1");
+    assert_finite(lat2, "lat2");
+    assert_finite(lon2, "lon2");
+
+    let (lat1, lat2) = (to_radian(lat1), to_radian(lat2));
+    let delta_lon = to_radian(lon2 - lon1);
+
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+
+    normalize_degree(to_degree(y.atan2(x)))
+}
+
+/// # Calculate Final Great Circle Bearing
+///
+/// ## Arguments
+///
+/// * `lat1`: Latitude of the departure point in decimal degrees, positive for north
+/// * `lon1`: Longitude of the departure point in decimal degrees, positive for east
+/// * `lat2`: Latitude of the destination in decimal degrees, positive for north
+/// * `lon2`: Longitude of the destination in decimal degrees, positive for east
+///
+/// returns: f64 True bearing on arrival at the destination in degrees from 0 to below 360
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::geodesy::*;
+///
+/// let course = final_bearing(50.0379, 8.5622, 49.9469, 8.9697);
+/// ```
+pub fn final_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    // The reverse of the initial bearing from the destination back to the departure point
+    normalize_degree(initial_bearing(lat2, lon2, lat1, lon1) + 180.0)
+}
+
+/// # Calculate Cross Track Distance
+///
+/// ## Arguments
+///
+/// * `lat`: Latitude of the aircraft in decimal degrees, positive for north
+/// * `lon`: Longitude of the aircraft in decimal degrees, positive for east
+/// * `leg_start_lat`: Latitude of the start of the leg in decimal degrees
+/// * `leg_start_lon`: Longitude of the start of the leg in decimal degrees
+/// * `leg_end_lat`: Latitude of the end of the leg in decimal degrees
+/// * `leg_end_lon`: Longitude of the end of the leg in decimal degrees
+///
+/// returns: f64 Distance from the great circle of the leg in NM, positive right and negative left of the track
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::geodesy::*;
+///
+/// let off_track = cross_track_distance(50.0, 8.7, 50.0379, 8.5622, 49.9469, 8.9697);
+/// ```
+pub fn cross_track_distance(lat: f64, lon: f64, leg_start_lat: f64, leg_start_lon: f64, leg_end_lat: f64, leg_end_lon: f64) -> f64 {
+    let (angular_distance, relative_bearing) = relative_to_leg(lat, lon, leg_start_lat, leg_start_lon, leg_end_lat, leg_end_lon);
+
+    (angular_distance.sin() * relative_bearing.sin()).asin() * EARTH_RADIUS
+}
+
+/// # Calculate Along Track Distance
+///
+/// ## Arguments
+///
+/// * `lat`: Latitude of the aircraft in decimal degrees, positive for north
+/// * `lon`: Longitude of the aircraft in decimal degrees, positive for east
+/// * `leg_start_lat`: Latitude of the start of the leg in decimal degrees
+/// * `leg_start_lon`: Longitude of the start of the leg in decimal degrees
+/// * `leg_end_lat`: Latitude of the end of the leg in decimal degrees
+/// * `leg_end_lon`: Longitude of the end of the leg in decimal degrees
+///
+/// returns: f64 Distance from the start of the leg to the point abeam the aircraft in NM, negative if the aircraft
+/// is still behind the start. Abeam the start of the leg it is 0.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::geodesy::*;
+///
+/// let progress = along_track_distance(50.0, 8.7, 50.0379, 8.5622, 49.9469, 8.9697);
+/// ```
+pub fn along_track_distance(lat: f64, lon: f64, leg_start_lat: f64, leg_start_lon: f64, leg_end_lat: f64, leg_end_lon: f64) -> f64 {
+    let (angular_distance, relative_bearing) = relative_to_leg(lat, lon, leg_start_lat, leg_start_lon, leg_end_lat, leg_end_lon);
+
+    (angular_distance.sin() * relative_bearing.cos()).atan2(angular_distance.cos()) * EARTH_RADIUS
+}
+
+// Angular distance from the start of the leg to the aircraft and the bearing to it relative to the leg, in radians
+fn relative_to_leg(lat: f64, lon: f64, leg_start_lat: f64, leg_start_lon: f64, leg_end_lat: f64, leg_end_lon: f64) -> (f64, f64) {
+    let angular_distance = great_circle_distance(leg_start_lat, leg_start_lon, lat, lon) / EARTH_RADIUS;
+    let relative_bearing = initial_bearing(leg_start_lat, leg_start_lon, lat, lon) - initial_bearing(leg_start_lat, leg_start_lon, leg_end_lat, leg_end_lon);
+
+    (angular_distance, to_radian(relative_bearing))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::utils::approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn great_circle_distance_identical() {
+        let result = great_circle_distance(50.0379, 8.5622, 50.0379, 8.5622);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn great_circle_distance_one_degree_latitude() {
+        let result = great_circle_distance(50.0, 8.0, 51.0, 8.0);
+        assert!(approx_eq(result, 60.04, 0.01), "{result}");
+    }
+
+    #[test]
+    fn great_circle_distance_quarter_equator() {
+        let result = great_circle_distance(0.0, 0.0, 0.0, 90.0);
+        assert!(approx_eq(result, EARTH_RADIUS * PI / 2.0, 1e-9), "{result}");
+    }
+
+    #[test]
+    fn great_circle_distance_antipodal() {
+        let result = great_circle_distance(45.0, 10.0, -45.0, -170.0);
+        assert!(approx_eq(result, EARTH_RADIUS * PI, 1e-6), "{result}");
+    }
+
+    #[test]
+    fn great_circle_distance_below_one_meter() {
+        // 1e-6° of latitude are about 0.11 m
+        let result = great_circle_distance(50.0, 8.0, 50.000001, 8.0);
+        assert!(approx_eq(result, EARTH_RADIUS * to_radian(1e-6), 1e-12), "{result}");
+        assert!(result > 0.0);
+    }
+
+    #[test]
+    fn initial_bearing_due_north() {
+        let result = initial_bearing(50.0, 8.0, 51.0, 8.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn initial_bearing_due_south() {
+        let result = initial_bearing(51.0, 8.0, 50.0, 8.0);
+        assert_eq!(result, 180.0);
+    }
+
+    #[test]
+    fn initial_bearing_along_equator() {
+        assert!(approx_eq(initial_bearing(0.0, 0.0, 0.0, 10.0), 90.0, 1e-9));
+        assert!(approx_eq(initial_bearing(0.0, 10.0, 0.0, 0.0), 270.0, 1e-9));
+    }
+
+    #[test]
+    fn initial_bearing_across_antimeridian() {
+        let result = initial_bearing(0.0, 179.0, 0.0, -179.0);
+        assert!(approx_eq(result, 90.0, 1e-9), "{result}");
+
+        let result = initial_bearing(0.0, -179.0, 0.0, 179.0);
+        assert!(approx_eq(result, 270.0, 1e-9), "{result}");
+    }
+
+    #[test]
+    fn final_bearing_due_north_and_south() {
+        assert_eq!(final_bearing(50.0, 8.0, 51.0, 8.0), 0.0);
+        assert_eq!(final_bearing(51.0, 8.0, 50.0, 8.0), 180.0);
+    }
+
+    #[test]
+    fn final_bearing_differs_from_initial_on_long_legs() {
+        // Eastbound on a great circle from mid latitudes the course turns south
+        let initial = initial_bearing(50.0, 0.0, 50.0, 60.0);
+        let result = final_bearing(50.0, 0.0, 50.0, 60.0);
+        assert!(initial < 90.0 && result > 90.0, "{initial} {result}");
+        assert!(approx_eq(result, 180.0 - initial, 1e-9), "{result}");
+    }
+
+    #[test]
+    fn cross_track_distance_sign() {
+        // One degree of latitude north and south of an eastbound leg along the equator
+        let result = cross_track_distance(1.0, 5.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, -60.04, 0.01), "{result}");
+
+        let result = cross_track_distance(-1.0, 5.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, 60.04, 0.01), "{result}");
+    }
+
+    #[test]
+    fn cross_track_distance_on_track() {
+        let result = cross_track_distance(0.0, 5.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, 0.0, 1e-9), "{result}");
+    }
+
+    #[test]
+    fn along_track_distance_halfway() {
+        let result = along_track_distance(-1.0, 5.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, 5.0 * 60.04, 0.1), "{result}");
+    }
+
+    #[test]
+    fn along_track_distance_abeam_leg_start() {
+        let result = along_track_distance(1.0, 0.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, 0.0, 1e-9), "{result}");
+        assert!(approx_eq(cross_track_distance(1.0, 0.0, 0.0, 0.0, 0.0, 10.0), -60.04, 0.01));
+    }
+
+    #[test]
+    fn along_track_distance_at_leg_start() {
+        assert_eq!(along_track_distance(0.0, 0.0, 0.0, 0.0, 0.0, 10.0), 0.0);
+        assert_eq!(cross_track_distance(0.0, 0.0, 0.0, 0.0, 0.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn along_track_distance_behind_leg_start() {
+        let result = along_track_distance(0.0, -1.0, 0.0, 0.0, 0.0, 10.0);
+        assert!(approx_eq(result, -60.04, 0.01), "{result}");
+    }
+}
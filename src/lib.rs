@@ -1,4 +1,64 @@
+pub mod dynamics;
 pub mod fk9;
+pub mod fuel;
+pub mod geodesy;
 pub mod meteorology;
 pub mod navigation;
 pub mod utils;
+
+use fk9::{calculate_takeoff_distance, Engine, GrassSurface, SurfaceCondition};
+use meteorology::{icao_temperature_fl, pressure_altitude_by_qnh};
+
+/// # Self Check
+///
+/// Runs a few reference calculations from the official sources and compares them with their known results, e.g. to
+/// verify a deployed build at startup where the tests can not be run.
+///
+/// returns: Result<(), String> Nothing, or a description of every calculation that does not match its reference
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::self_check;
+///
+/// self_check().unwrap();
+/// ```
+pub fn self_check() -> Result<(), String> {
+    let takeoff = calculate_takeoff_distance(Engine::Rotax912Uls, 600.0, 0.0, 15.0, 0.0, Some(GrassSurface::default()), SurfaceCondition::Inconspicuous)
+        .map_err(|error| error.to_string());
+
+    let checks = [
+        ("ISA temperature at FL0", icao_temperature_fl(0).map_err(|error| error.to_string()), 15.0),
+        ("ISA temperature at FL100", icao_temperature_fl(100).map_err(|error| error.to_string()), -4.81),
+        ("ISA temperature at FL360", icao_temperature_fl(360).map_err(|error| error.to_string()), -56.32),
+        ("Pressure altitude at QNH 1021 hPa and 113 m", pressure_altitude_by_qnh(1021.0, 113.0).map_err(|error| error.to_string()), 48.71),
+        ("FK9 ULS takeoff run at 600 kg on grass", takeoff.clone().map(|(takeoff_run, _)| takeoff_run), 153.0),
+        ("FK9 ULS distance to 50 ft at 600 kg on grass", takeoff.map(|(_, to_50_feet)| to_50_feet), 375.0),
+    ];
+
+    let mismatches: Vec<String> = checks
+        .into_iter()
+        .filter_map(|(name, result, expected)| match result {
+            Ok(result) if result == expected => None,
+            Ok(result) => Some(format!("{name}: expected {expected}, got {result}")),
+            Err(error) => Some(format!("{name}: expected {expected}, got error: {error}")),
+        })
+        .collect();
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_check_passes() {
+        let result = self_check();
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -1,5 +1,9 @@
+use std::sync::OnceLock;
+
 use snafu::prelude::*;
 
+use crate::utils::{assert_finite, feet_to_meter, inhg_to_hpa, meter_to_feet};
+
 // https://www.dwd.de/DE/service/lexikon/begriffe/S/Standardatmosphaere_pdf.pdf?__blob=publicationFile&v=3
 const ISA_TEMPERATURE: f64 = 288.15_f64; /* K */
 const ISA_PRESSURE: f64 = 1013.25_f64; /* hPa */
@@ -9,6 +13,8 @@ const SPECIFIC_GAS_CONSTANT: f64 = 287.058_f64;
 const GRAVITATIONAL_ACCELERATION: f64 = 9.81_f64; /* m/s */
 const ICAO_MINIMUM_PRESSURE_ALTITUDE: f64 = -1_000.0_f64; /* m */
 const ICAO_MAXIMUM_PRESSURE_ALTITUDE: f64 = 80_000.0_f64; /* m */
+const KELVIN: f64 = 273.15_f64;
+const EARTH_RADIUS: f64 = 6_356_766.0_f64; /* m */
 
 #[derive(Debug, Clone, Copy)]
 struct AtmosphericLevel {
@@ -51,6 +57,69 @@ pub enum UndefinedPressureAltitudeError {
 
     #[snafu(display("The pressure altitude {pressure_altitude} m is above the maximum defined ({max} m) in the ICAO Standard Atmosphere"))]
     AboveMaximum { max: f64, pressure_altitude: f64 },
+
+    #[snafu(display("The pressure altitude {pressure_altitude} m is not a finite number"))]
+    NotFinite { pressure_altitude: f64 },
+}
+
+/// Pressure altitude in meters validated to be within the ICAO Standard Atmosphere
+///
+/// The public functions of this module keep taking the pressure altitude as `f64` in meters, so existing callers keep
+/// working, and validate it through this type. Only `pressure_altitude_by_qnh` changed, returning a `Result` as its
+/// calculated pressure altitude is validated the same way.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PressureAltitude(f64);
+
+impl PressureAltitude {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `pressure_altitude`: Pressure altitude in meters
+    ///
+    /// returns: Result<PressureAltitude, UndefinedPressureAltitudeError>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aviation_calculator::meteorology::*;
+    ///
+    /// let pressure_altitude = PressureAltitude::new(113.7).unwrap();
+    /// ```
+    pub fn new(pressure_altitude: f64) -> Result<Self, UndefinedPressureAltitudeError> {
+        if !pressure_altitude.is_finite() {
+            return Err(UndefinedPressureAltitudeError::NotFinite { pressure_altitude });
+        }
+
+        if pressure_altitude < ICAO_MINIMUM_PRESSURE_ALTITUDE {
+            return Err(UndefinedPressureAltitudeError::BelowMinimum { min: ICAO_MINIMUM_PRESSURE_ALTITUDE, pressure_altitude });
+        }
+
+        if pressure_altitude > ICAO_MAXIMUM_PRESSURE_ALTITUDE {
+            return Err(UndefinedPressureAltitudeError::AboveMaximum { max: ICAO_MAXIMUM_PRESSURE_ALTITUDE, pressure_altitude });
+        }
+
+        Ok(PressureAltitude(pressure_altitude))
+    }
+
+    /// Pressure altitude in meters
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for PressureAltitude {
+    type Error = UndefinedPressureAltitudeError;
+
+    fn try_from(pressure_altitude: f64) -> Result<Self, Self::Error> {
+        PressureAltitude::new(pressure_altitude)
+    }
+}
+
+impl From<PressureAltitude> for f64 {
+    fn from(pressure_altitude: PressureAltitude) -> Self {
+        pressure_altitude.value()
+    }
 }
 
 ///
@@ -69,17 +138,114 @@ pub enum UndefinedPressureAltitudeError {
 /// let temp: f64 = icao_temperature(113.7).unwrap();
 /// ```
 pub fn icao_temperature(pressure_altitude: f64) -> Result<f64, UndefinedPressureAltitudeError> {
-    if pressure_altitude < ICAO_MINIMUM_PRESSURE_ALTITUDE {
-        return Err(UndefinedPressureAltitudeError::BelowMinimum { min: ICAO_MINIMUM_PRESSURE_ALTITUDE, pressure_altitude });
-    }
+    let pressure_altitude = PressureAltitude::new(pressure_altitude)?.value();
+    let current_level = atmospheric_level_by_geopotential_altitude(pressure_altitude);
 
-    if pressure_altitude > ICAO_MAXIMUM_PRESSURE_ALTITUDE {
-        return Err(UndefinedPressureAltitudeError::AboveMaximum { max: ICAO_MAXIMUM_PRESSURE_ALTITUDE, pressure_altitude });
-    }
+    Ok(round(current_level.base_temperature - (pressure_altitude - current_level.base as f64) * current_level.lapse_rate, 2))
+}
+
+/// # ICAO Standard Temperature by Flight Level
+///
+/// ## Arguments
+///
+/// * `flight_level`: Flight level in hundreds of feet
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Default temperature for the given flight level
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let temp: f64 = icao_temperature_fl(100).unwrap();
+/// ```
+pub fn icao_temperature_fl(flight_level: u32) -> Result<f64, UndefinedPressureAltitudeError> {
+    icao_temperature(feet_to_meter(flight_level as f64 * 100.0))
+}
+
+/// # ICAO Standard Temperature by Geometric Altitude
+///
+/// The ICAO standard atmosphere is defined by geopotential altitude, which falls increasingly below the geometric
+/// altitude, e.g. from GPS, with height. The geometric altitude is converted accordingly first.
+///
+/// ## Arguments
+///
+/// * `geometric_ft`: Geometric altitude in ft
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Default temperature for the given altitude
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let temp: f64 = icao_temperature_geometric_ft(45000.0).unwrap();
+/// ```
+pub fn icao_temperature_geometric_ft(geometric_ft: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    assert_finite(geometric_ft, "geometric_ft");
 
+    icao_temperature(geopotential_altitude(feet_to_meter(geometric_ft)))
+}
+
+fn geopotential_altitude(geometric_altitude: f64) -> f64 {
+    EARTH_RADIUS * geometric_altitude / (EARTH_RADIUS + geometric_altitude)
+}
+
+/// # ICAO Standard Pressure
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude in meters
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Standard pressure for the given pressure altitude in hPa
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let pressure: f64 = pressure_at_altitude(3048.0).unwrap();
+/// ```
+pub fn pressure_at_altitude(pressure_altitude: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    let pressure_altitude = PressureAltitude::new(pressure_altitude)?.value();
     let current_level = atmospheric_level_by_geopotential_altitude(pressure_altitude);
 
-    Ok(round(current_level.base_temperature - (pressure_altitude - current_level.base as f64) * current_level.lapse_rate, 2))
+    let base_pressure = LEVELS
+        .windows(2)
+        .take_while(|levels| levels[1].base <= current_level.base)
+        .fold(ISA_PRESSURE, |pressure, levels| level_pressure(levels[0], pressure, (levels[1].base - levels[0].base) as f64));
+
+    Ok(round(level_pressure(current_level, base_pressure, pressure_altitude - current_level.base as f64), 2))
+}
+
+/// # ICAO Standard Atmosphere by Flight Level
+///
+/// returns: &[(u32, f64, f64)] Flight level, temperature in °C and pressure in hPa from FL0 to FL400 in steps of FL50
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let (flight_level, temperature, pressure) = isa_flight_level_reference()[2];
+/// ```
+pub fn isa_flight_level_reference() -> &'static [(u32, f64, f64)] {
+    static REFERENCE: OnceLock<Vec<(u32, f64, f64)>> = OnceLock::new();
+
+    REFERENCE.get_or_init(|| {
+        (0..=400)
+            .step_by(50)
+            .map(|flight_level| {
+                let pressure_altitude = feet_to_meter(flight_level as f64 * 100.0);
+
+                (
+                    flight_level,
+                    icao_temperature(pressure_altitude).unwrap(),
+                    pressure_at_altitude(pressure_altitude).unwrap(),
+                )
+            })
+            .collect()
+    })
 }
 
 /// # Calculate Pressure Altitude by QNH and Field Elevation
@@ -89,6 +255,54 @@ pub fn icao_temperature(pressure_altitude: f64) -> Result<f64, UndefinedPressure
 /// * `qnh`: QNH for the location given in hPa
 /// * `field_elevation`: Field elevation given in meters
 ///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Pressure altitude in meters, or an error if it is not defined
+/// by the ICAO Standard Atmosphere
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let pressure: f64 = pressure_altitude_by_qnh(1021.0, 113.7).unwrap();
+/// ```
+pub fn pressure_altitude_by_qnh(qnh: f64, field_elevation: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    let pressure_altitude = PressureAltitude::new(pressure_altitude_by_qnh_raw(qnh, field_elevation))?;
+
+    Ok(round(pressure_altitude.value(), 2))
+}
+
+/// # Calculate Pressure Altitude by Altimeter Setting in inHg and Field Elevation in ft
+///
+/// ## Arguments
+///
+/// * `altimeter_inhg`: Altimeter setting (QNH) for the location given in inHg
+/// * `field_elevation_ft`: Field elevation given in ft
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Pressure altitude in ft, or an error if it is not defined by the
+/// ICAO Standard Atmosphere
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let pressure_altitude: f64 = pressure_altitude_by_altimeter_inhg(30.15, 373.0).unwrap();
+/// ```
+pub fn pressure_altitude_by_altimeter_inhg(altimeter_inhg: f64, field_elevation_ft: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    let pressure_altitude = pressure_altitude_by_qnh(inhg_to_hpa(altimeter_inhg), feet_to_meter(field_elevation_ft))?;
+
+    Ok(round(meter_to_feet(pressure_altitude), 2))
+}
+
+/// # Calculate Unrounded Pressure Altitude by QNH and Field Elevation
+///
+/// Same as `pressure_altitude_by_qnh`, but keeps the full precision for further calculations.
+///
+/// ## Arguments
+///
+/// * `qnh`: QNH for the location given in hPa
+/// * `field_elevation`: Field elevation given in meters
+///
 /// returns: f64
 ///
 /// # Examples
@@ -96,16 +310,90 @@ pub fn icao_temperature(pressure_altitude: f64) -> Result<f64, UndefinedPressure
 /// ```
 /// use aviation_calculator::meteorology::*;
 ///
-/// let pressure: f64 = pressure_altitude_by_qnh(1021.0, 113.7);
+/// let pressure: f64 = pressure_altitude_by_qnh_raw(1021.0, 113.7);
 /// ```
-pub fn pressure_altitude_by_qnh(qnh: f64, field_elevation: f64) -> f64 {
-    round(field_elevation
-              + ISA_TEMPERATURE / TROPOSPHERIC_TEMPERATURE_LAPSE
-        * (1.0_f64
-        - (qnh / ISA_PRESSURE).powf(
-        SPECIFIC_GAS_CONSTANT * TROPOSPHERIC_TEMPERATURE_LAPSE
-            / GRAVITATIONAL_ACCELERATION,
-    )), 2)
+pub fn pressure_altitude_by_qnh_raw(qnh: f64, field_elevation: f64) -> f64 {
+    assert_finite(qnh, "qnh");
+    assert_finite(field_elevation, "field_elevation");
+
+    field_elevation + tropospheric_altitude_by_pressure(qnh)
+}
+
+/// # Calculate Indicated Altitude by Pressure Altitude and QNH
+///
+/// The inverse of `pressure_altitude_by_qnh`, e.g. to show a performance calculation done in pressure altitude as
+/// the altitude the altimeter indicates with the local QNH set.
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude given in meters
+/// * `qnh`: QNH for the location given in hPa
+///
+/// returns: f64 Indicated altitude in meters
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let indicated_altitude: f64 = indicated_altitude_from_pressure_altitude(48.71, 1021.0);
+/// ```
+pub fn indicated_altitude_from_pressure_altitude(pressure_altitude: f64, qnh: f64) -> f64 {
+    assert_finite(pressure_altitude, "pressure_altitude");
+    assert_finite(qnh, "qnh");
+
+    round(pressure_altitude - tropospheric_altitude_by_pressure(qnh), 2)
+}
+
+/// # Calculate Pressure Altitude by QFE
+///
+/// Unlike `pressure_altitude_by_qnh`, this takes no field elevation. The QFE is the pressure at the field itself, so
+/// the pressure altitude follows from it directly, and the elevation would cancel out of the calculation. Use
+/// `qnh_by_qfe` if the QNH is needed as well.
+///
+/// ## Arguments
+///
+/// * `qfe`: QFE for the location given in hPa
+///
+/// returns: f64 Pressure altitude in meters
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let pressure: f64 = pressure_altitude_by_qfe(1007.5);
+/// ```
+pub fn pressure_altitude_by_qfe(qfe: f64) -> f64 {
+    assert_finite(qfe, "qfe");
+
+    round(tropospheric_altitude_by_pressure(qfe), 2)
+}
+
+/// # Convert QFE to QNH
+///
+/// The conversion is consistent with `pressure_altitude_by_qnh`, so both QFE and the resulting QNH yield the same
+/// pressure altitude for the field.
+///
+/// ## Arguments
+///
+/// * `qfe`: QFE for the location given in hPa
+/// * `field_elevation`: Field elevation given in meters
+///
+/// returns: f64 QNH in hPa
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let qnh: f64 = qnh_by_qfe(1007.5, 113.7);
+/// ```
+pub fn qnh_by_qfe(qfe: f64, field_elevation: f64) -> f64 {
+    assert_finite(qfe, "qfe");
+    assert_finite(field_elevation, "field_elevation");
+
+    round(tropospheric_pressure_by_altitude(tropospheric_altitude_by_pressure(qfe) - field_elevation), 2)
 }
 
 ///
@@ -125,9 +413,82 @@ pub fn pressure_altitude_by_qnh(qnh: f64, field_elevation: f64) -> f64 {
 /// let temperature_deviation = calculate_temperature_deviation(113.0, 21.0);
 /// ```
 pub fn calculate_temperature_deviation(pressure_altitude: f64, temperature: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    assert_finite(temperature, "temperature");
+
     Ok(round(temperature - icao_temperature(pressure_altitude)?, 2))
 }
 
+/// # Calculate ISA Deviation
+///
+/// Same as `calculate_temperature_deviation`, the deviation from the ICAO standard atmosphere is also known as ISA
+/// deviation.
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude in meters
+/// * `temperature`: Current temperature in °C
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Deviation from the ISA temperature in °C
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let isa_deviation = isa_deviation(113.0, 21.0);
+/// ```
+pub fn isa_deviation(pressure_altitude: f64, temperature: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    calculate_temperature_deviation(pressure_altitude, temperature)
+}
+
+/// # Calculate Density Altitude
+///
+/// Approximated by 120 ft per °C deviation from the ICAO standard temperature.
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude in meters
+/// * `temperature`: Current temperature in °C
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Density altitude in meters
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let density_altitude = density_altitude(1524.0, 30.0).unwrap();
+/// ```
+pub fn density_altitude(pressure_altitude: f64, temperature: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    let temperature_deviation = calculate_temperature_deviation(pressure_altitude, temperature)?;
+
+    Ok(round(pressure_altitude + feet_to_meter(120.0 * temperature_deviation), 2))
+}
+
+/// # Calculate Air Density
+///
+/// Uses the standard pressure at the given pressure altitude and the ideal gas law.
+///
+/// ## Arguments
+///
+/// * `pressure_altitude`: Pressure altitude in meters
+/// * `temperature`: Current temperature in °C
+///
+/// returns: Result<f64, UndefinedPressureAltitudeError> Air density in kg/m³
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::meteorology::*;
+///
+/// let density = air_density(1524.0, 30.0).unwrap();
+/// ```
+pub fn air_density(pressure_altitude: f64, temperature: f64) -> Result<f64, UndefinedPressureAltitudeError> {
+    let pressure = pressure_at_altitude(pressure_altitude)? * 100.0;
+
+    Ok(round(pressure / (SPECIFIC_GAS_CONSTANT * (temperature + KELVIN)), 4))
+}
+
 fn atmospheric_level_by_geopotential_altitude<'a>(elevation: f64) -> &'a AtmosphericLevel {
     LEVELS.iter()
         .take_while(|level| elevation >= level.base as f64)
@@ -135,6 +496,34 @@ fn atmospheric_level_by_geopotential_altitude<'a>(elevation: f64) -> &'a Atmosph
         .unwrap_or(LEVELS.first().unwrap())
 }
 
+fn level_pressure(level: &AtmosphericLevel, base_pressure: f64, height_above_base: f64) -> f64 {
+    let base_temperature = level.base_temperature + KELVIN;
+
+    if level.lapse_rate == 0.0 {
+        return base_pressure * (-GRAVITATIONAL_ACCELERATION * height_above_base / (SPECIFIC_GAS_CONSTANT * base_temperature)).exp();
+    }
+
+    base_pressure
+        * (1.0_f64 - level.lapse_rate * height_above_base / base_temperature)
+            .powf(GRAVITATIONAL_ACCELERATION / (SPECIFIC_GAS_CONSTANT * level.lapse_rate))
+}
+
+fn tropospheric_altitude_by_pressure(pressure: f64) -> f64 {
+    ISA_TEMPERATURE / TROPOSPHERIC_TEMPERATURE_LAPSE
+        * (1.0_f64
+        - (pressure / ISA_PRESSURE).powf(
+        SPECIFIC_GAS_CONSTANT * TROPOSPHERIC_TEMPERATURE_LAPSE
+            / GRAVITATIONAL_ACCELERATION,
+    ))
+}
+
+fn tropospheric_pressure_by_altitude(altitude: f64) -> f64 {
+    ISA_PRESSURE
+        * (1.0_f64 - TROPOSPHERIC_TEMPERATURE_LAPSE * altitude / ISA_TEMPERATURE).powf(
+        GRAVITATIONAL_ACCELERATION / (SPECIFIC_GAS_CONSTANT * TROPOSPHERIC_TEMPERATURE_LAPSE),
+    )
+}
+
 fn round(x: f64, decimals: u8) -> f64 {
     let y = 10_i32.pow(decimals.into()) as f64;
     (x * y).round() / y
@@ -142,24 +531,155 @@ fn round(x: f64, decimals: u8) -> f64 {
 
 #[cfg(test)]
 mod tests {
+    use crate::utils::approx_eq;
+
     use super::*;
 
     #[test]
     fn pressure_altitude_example_1() {
         let result = pressure_altitude_by_qnh(1021.0, 113.0);
-        assert_eq!(result, 48.71);
+        assert_eq!(result.unwrap(), 48.71);
     }
 
     #[test]
     fn pressure_altitude_example_2() {
         let result = pressure_altitude_by_qnh(1013.25, 113.0);
-        assert_eq!(result, 113.0);
+        assert_eq!(result.unwrap(), 113.0);
+    }
+
+    #[test]
+    fn pressure_altitude_by_qnh_above_maximum() {
+        let result = pressure_altitude_by_qnh(1013.25, 80_100.0);
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::AboveMaximum { .. })));
+    }
+
+    #[test]
+    fn pressure_altitude_by_qnh_below_minimum() {
+        let result = pressure_altitude_by_qnh(1013.25, -1_100.0);
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::BelowMinimum { .. })));
     }
 
     #[test]
     fn pressure_altitude_example_3() {
         let result = pressure_altitude_by_qnh(1021.0, 113.0);
-        assert_eq!(result, 48.71);
+        assert_eq!(result.unwrap(), 48.71);
+    }
+
+    #[test]
+    fn pressure_at_sea_level() {
+        let result = pressure_at_altitude(0.0);
+        assert_eq!(result.unwrap(), 1013.25);
+    }
+
+    #[test]
+    fn pressure_at_altitude_matches_troposphere() {
+        let result = pressure_at_altitude(3048.0);
+        assert_eq!(result.unwrap(), round(tropospheric_pressure_by_altitude(3048.0), 2));
+    }
+
+    #[test]
+    fn pressure_at_tropopause() {
+        let result = pressure_at_altitude(12192.0);
+        assert!(approx_eq(result.unwrap(), 187.5, 0.1));
+    }
+
+    #[test]
+    fn isa_flight_level_reference_fl100() {
+        let (flight_level, temperature, _) = isa_flight_level_reference()[2];
+        assert_eq!(flight_level, 100);
+        assert_eq!(temperature, icao_temperature_fl(100).unwrap());
+    }
+
+    #[test]
+    fn isa_flight_level_reference_range() {
+        let result = isa_flight_level_reference();
+        assert_eq!(result.len(), 9);
+        assert_eq!(result.first().unwrap(), &(0, 15.0, 1013.25));
+        assert_eq!(result.last().unwrap().0, 400);
+    }
+
+    #[test]
+    fn pressure_altitude_by_altimeter_inhg_standard() {
+        let result = pressure_altitude_by_altimeter_inhg(29.92, 1000.0).unwrap();
+        assert!(approx_eq(result, 1000.0, 2.0), "{result}");
+    }
+
+    #[test]
+    fn pressure_altitude_by_altimeter_inhg_above_maximum() {
+        let result = pressure_altitude_by_altimeter_inhg(29.92, 263_000.0);
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::AboveMaximum { .. })));
+    }
+
+    #[test]
+    fn pressure_altitude_raw() {
+        let result = pressure_altitude_by_qnh_raw(1021.0, 113.0);
+        assert_eq!(result, 48.70703054690429);
+    }
+
+    #[test]
+    fn indicated_altitude_standard() {
+        let result = indicated_altitude_from_pressure_altitude(113.0, 1013.25);
+        assert_eq!(result, 113.0);
+    }
+
+    #[test]
+    fn indicated_altitude_round_trip() {
+        for (qnh, field_elevation) in [(1021.0, 113.0), (995.0, 450.0), (1035.5, 0.0)] {
+            let pressure_altitude = pressure_altitude_by_qnh_raw(qnh, field_elevation);
+            let result = indicated_altitude_from_pressure_altitude(pressure_altitude, qnh);
+            assert_eq!(result, field_elevation);
+        }
+    }
+
+    #[test]
+    fn pressure_altitude_by_qfe_standard() {
+        let result = pressure_altitude_by_qfe(1013.25);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn pressure_altitude_by_qfe_example() {
+        let result = pressure_altitude_by_qfe(1007.5);
+        assert_eq!(result, 47.96);
+    }
+
+    #[test]
+    fn qnh_by_qfe_standard() {
+        let result = qnh_by_qfe(1000.0, tropospheric_altitude_by_pressure(1000.0));
+        assert_eq!(result, 1013.25);
+    }
+
+    #[test]
+    fn pressure_altitude_by_qfe_matches_qnh() {
+        let qfe = 1007.5;
+        let field_elevation = 113.7;
+        let by_qfe = pressure_altitude_by_qfe(qfe);
+        let by_qnh = pressure_altitude_by_qnh(qnh_by_qfe(qfe, field_elevation), field_elevation).unwrap();
+        assert!(approx_eq(by_qfe, by_qnh, 0.1), "{by_qfe} m by QFE differs from {by_qnh} m by QNH");
+    }
+
+    #[test]
+    fn pressure_altitude_below_minimum() {
+        let result = PressureAltitude::new(-1001.0);
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::BelowMinimum { .. })));
+    }
+
+    #[test]
+    fn pressure_altitude_maximum() {
+        let result = PressureAltitude::new(80_000.0);
+        assert_eq!(result.unwrap().value(), 80_000.0);
+    }
+
+    #[test]
+    fn pressure_altitude_not_finite() {
+        assert!(matches!(PressureAltitude::new(f64::NAN), Err(UndefinedPressureAltitudeError::NotFinite { .. })));
+        assert!(matches!(PressureAltitude::new(f64::INFINITY), Err(UndefinedPressureAltitudeError::NotFinite { .. })));
+    }
+
+    #[test]
+    fn pressure_altitude_try_from() {
+        let result: Result<PressureAltitude, _> = 80_000.1.try_into();
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::AboveMaximum { .. })));
     }
 
     #[test]
@@ -258,6 +778,43 @@ mod tests {
         assert_eq!(result.unwrap(), -44.5_f64);
     }
 
+    #[test]
+    fn isa_temperature_fl0() {
+        let result = icao_temperature_fl(0);
+        assert_eq!(result.unwrap(), 15.0_f64);
+    }
+
+    #[test]
+    fn isa_temperature_fl100() {
+        let result = icao_temperature_fl(100);
+        assert_eq!(result.unwrap(), -4.81_f64);
+    }
+
+    #[test]
+    fn isa_temperature_fl360() {
+        let result = icao_temperature_fl(360);
+        assert_eq!(result.unwrap(), -56.32_f64);
+    }
+
+    #[test]
+    fn isa_temperature_fl400_tropopause() {
+        let result = icao_temperature_fl(400);
+        assert_eq!(result.unwrap(), -56.5_f64);
+    }
+
+    #[test]
+    fn isa_temperature_geometric_low_altitude() {
+        let result = icao_temperature_geometric_ft(1000.0);
+        assert!(approx_eq(result.unwrap(), icao_temperature(feet_to_meter(1000.0)).unwrap(), 0.01));
+    }
+
+    #[test]
+    fn isa_temperature_geometric_high_altitude() {
+        let result = icao_temperature_geometric_ft(30000.0);
+        assert_eq!(result.unwrap(), -44.35);
+        assert_eq!(icao_temperature(feet_to_meter(30000.0)).unwrap(), -44.44);
+    }
+
     #[test]
     fn isa_temperature_out_of_range_positive() {
         let result = icao_temperature(80_000.01_f64);
@@ -311,4 +868,46 @@ mod tests {
         let result = calculate_temperature_deviation(200.0, 15.0);
         assert_eq!(result.unwrap(), 1.3);
     }
+
+    #[test]
+    fn isa_deviation_equals_temperature_deviation() {
+        let result = isa_deviation(200.0, 15.0);
+        assert_eq!(result.unwrap(), calculate_temperature_deviation(200.0, 15.0).unwrap());
+    }
+
+    #[test]
+    fn density_altitude_standard() {
+        let result = density_altitude(0.0, 15.0);
+        assert_eq!(result.unwrap(), 0.0);
+    }
+
+    #[test]
+    fn density_altitude_hot_and_high() {
+        let result = density_altitude(1524.0, 30.0);
+        assert_eq!(result.unwrap(), 2435.11);
+    }
+
+    #[test]
+    fn density_altitude_undefined_pressure_altitude() {
+        let result = density_altitude(80_000.01, 15.0);
+        assert!(matches!(result, Err(UndefinedPressureAltitudeError::AboveMaximum { .. })));
+    }
+
+    #[test]
+    fn air_density_sea_level_isa() {
+        let result = air_density(0.0, 15.0);
+        assert_eq!(result.unwrap(), 1.225);
+    }
+
+    #[test]
+    fn air_density_hot_and_high() {
+        let result = air_density(1524.0, 30.0);
+        assert_eq!(result.unwrap(), 0.9687);
+    }
+
+    #[test]
+    fn air_density_undefined_pressure_altitude() {
+        assert!(matches!(air_density(-1_000.01, 15.0), Err(UndefinedPressureAltitudeError::BelowMinimum { .. })));
+        assert!(matches!(air_density(80_000.01, 15.0), Err(UndefinedPressureAltitudeError::AboveMaximum { .. })));
+    }
 }
@@ -1,5 +1,116 @@
+use std::time::Duration;
+
+use snafu::prelude::*;
+
 use crate::utils::*;
 
+// Wind correction angles above are hardly ever flown and hint at swapped inputs
+const LARGE_WIND_CORRECTION: f64 = 30.0; /* ° */
+
+#[derive(Debug, Snafu)]
+pub enum NavigationError {
+    #[snafu(display("The course {dc}° can not be flown with {tas} TAS against a wind of {ws} from {wd}°"))]
+    CourseUnflyable { dc: f64, tas: f64, wd: f64, ws: f64 },
+
+    #[snafu(display("The true air speed must not be zero"))]
+    ZeroAirspeed,
+
+    #[snafu(display("The true air speed {tas} must not be negative"))]
+    NegativeAirspeed { tas: f64 },
+
+    #[snafu(display("Two of air vector, ground vector and wind, or course, TAS and wind are required to solve the wind triangle"))]
+    InsufficientInputs,
+
+    #[snafu(display("The required ground speed {gs} must be finite and greater than zero"))]
+    InvalidGroundSpeed { gs: f64 },
+
+    #[snafu(display("The wind of {ws} from {wd}° alone already exceeds the required ground speed {gs} along the course {dc}°"))]
+    CarriedByWind { dc: f64, gs: f64, wd: f64, ws: f64 },
+}
+
+#[derive(Debug, Snafu)]
+pub enum WindParseError {
+    #[snafu(display("The wind \"{input}\" is not given as direction/speed, direction@speed or in the 5 digit METAR format"))]
+    InvalidFormat { input: String },
+
+    #[snafu(display("The wind direction {direction}° is not within 0° and 360°"))]
+    DirectionOutOfRange { direction: f64 },
+
+    #[snafu(display("The wind speed {speed} must not be negative"))]
+    NegativeSpeed { speed: f64 },
+}
+
+/// Plausibility warning of a navigation result that is still calculated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Warning {
+    /// The wind correction angle in degrees exceeds 30°, e.g. because wind speed and TAS were swapped
+    LargeWindCorrection { wca: f64 },
+}
+
+/// Direction of the wind component along the runway or track
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlongTrack {
+    Head,
+    Tail,
+}
+
+/// Side from which the crosswind component blows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossSide {
+    Left,
+    Right,
+}
+
+/// Wind components with their signs expressed as enums
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindComponents {
+    pub headwind: f64,
+    pub crosswind: f64,
+    pub along_track: AlongTrack,
+    pub cross_side: CrossSide,
+}
+
+/// Wind correction angle, flagged as not achievable if the crosswind component exceeds the TAS
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WcaResult {
+    pub wca: f64,
+    pub achievable: bool,
+}
+
+/// All navigation outputs for a single leg
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegAnalysis {
+    pub heading: f64,
+    pub wind_correction_angle: f64,
+    pub drift_angle: f64,
+    pub ground_speed: f64,
+    pub leg_time: f64,
+    pub headwind: f64,
+    pub crosswind: f64,
+}
+
+/// Known values of a wind triangle, directions in degrees and speeds in the same unit
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WindTriangleInputs {
+    pub course: Option<f64>,
+    pub heading: Option<f64>,
+    pub tas: Option<f64>,
+    pub gs: Option<f64>,
+    pub wd: Option<f64>,
+    pub ws: Option<f64>,
+}
+
+/// Complete wind triangle, directions in degrees between 0 and 360
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindTriangleSolution {
+    pub course: f64,
+    pub heading: f64,
+    pub tas: f64,
+    pub gs: f64,
+    pub wd: f64,
+    pub ws: f64,
+}
+
 /// # Calculate Ground Speed (GS)
 ///
 /// ## Arguments
@@ -19,6 +130,11 @@ use crate::utils::*;
 /// let gs = ground_speed(140.0, 110.0, 90.0, 12.0);
 /// ```
 pub fn ground_speed(course: f64, tas: f64, wd: f64, ws: f64) -> f64 {
+    assert_finite(course, "course");
+    assert_finite(tas, "tas");
+    assert_finite(wd, "wd");
+    assert_finite(ws, "ws");
+
     if ws == 0.0 {
         return tas;
     }
@@ -30,6 +146,31 @@ pub fn ground_speed(course: f64, tas: f64, wd: f64, ws: f64) -> f64 {
     round(tas * (1.0 - swc.powi(2)).sqrt() - (ws * (wind_dir - crs).cos()), 2)
 }
 
+/// # Calculate Ground Speed (GS) with Check for Unflyable Courses
+///
+/// ## Arguments
+///
+/// * `course`: Course in degrees
+/// * `tas`: True Air Speed (TAS) in any unit
+/// * `wd`: Wind Direction (WD) in degrees
+/// * `ws`: Wind Speed (WS) in the same unit as tas
+///
+/// returns: Result<f64, NavigationError> GS in the same unit as TAS is provided, or an error if the TAS is not
+/// positive or the crosswind component exceeds the TAS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let gs = ground_speed_checked(140.0, 110.0, 90.0, 12.0).unwrap();
+/// ```
+pub fn ground_speed_checked(course: f64, tas: f64, wd: f64, ws: f64) -> Result<f64, NavigationError> {
+    validate_wind_triangle(course, tas, wd, ws)?;
+
+    Ok(ground_speed(course, tas, wd, ws))
+}
+
 /// # Calculate Wind Correction Angle (WCA)
 ///
 /// ## Arguments
@@ -38,7 +179,7 @@ pub fn ground_speed(course: f64, tas: f64, wd: f64, ws: f64) -> f64 {
 /// * `ws`: Wind Speed (WS) in the same unit as tas
 /// * `awa`: Acute Wind Angle (AWA) in degrees
 ///
-/// returns: f64 Wind Correction Angle (WCA) in degrees
+/// returns: f64 Wind Correction Angle (WCA) in degrees, limited to ±90° if the crosswind component exceeds the TAS
 ///
 /// # Examples
 ///
@@ -48,11 +189,103 @@ pub fn ground_speed(course: f64, tas: f64, wd: f64, ws: f64) -> f64 {
 /// let wca = wind_correction_angle(110.0, 12.0, 20.0);
 /// ```
 pub fn wind_correction_angle(tas: f64, ws: f64, awa: f64) -> f64 {
+    wind_correction_angle_detailed(tas, ws, awa).wca
+}
+
+/// # Calculate Wind Correction Angle (WCA) with Achievability
+///
+/// ## Arguments
+///
+/// * `tas`: True Air Speed (TAS) in any unit
+/// * `ws`: Wind Speed (WS) in the same unit as tas
+/// * `awa`: Acute Wind Angle (AWA) in degrees
+///
+/// returns: WcaResult Wind Correction Angle (WCA) in degrees, limited to ±90°, and whether the crosswind component
+/// can be compensated at all
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let result = wind_correction_angle_detailed(110.0, 12.0, 20.0);
+/// assert!(result.achievable);
+/// ```
+pub fn wind_correction_angle_detailed(tas: f64, ws: f64, awa: f64) -> WcaResult {
+    assert_finite(tas, "tas");
+    assert_finite(ws, "ws");
+    assert_finite(awa, "awa");
+
+    let awa = signed_wind_angle(awa);
+
     if awa == 0.0 || awa == 180.0 || ws == 0.0 {
-        return 0.0;
+        return WcaResult { wca: 0.0, achievable: true };
+    }
+
+    let ratio = ws / tas * to_radian(awa).sin();
+
+    WcaResult {
+        wca: round(to_degree(ratio.clamp(-1.0, 1.0).asin()), 2),
+        achievable: ratio.abs() <= 1.0,
     }
+}
+
+/// # Calculate Acute Wind Angle (AWA)
+///
+/// ## Arguments
+///
+/// * `wd`: Wind Direction (WD) in degrees
+/// * `dc`: Desired Course (DC) in degrees
+///
+/// returns: f64 Signed angle between wind and course in degrees between -180 and 180, positive for wind from the right
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let awa = acute_wind_angle(20.0, 350.0);
+/// assert_eq!(awa, 30.0);
+/// ```
+pub fn acute_wind_angle(wd: f64, dc: f64) -> f64 {
+    assert_finite(wd, "wd");
+    assert_finite(dc, "dc");
+
+    signed_wind_angle(wd - dc)
+}
+
+/// # Calculate Relative Wind
+///
+/// ## Arguments
+///
+/// * `course`: Course in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+///
+/// returns: (f64, CrossSide) Angle between wind and course in degrees between 0 and 180 and the side the wind is
+/// coming from. Wind from straight ahead or behind is classified as from the right.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (angle, side) = relative_wind(350.0, 20.0);
+/// assert_eq!((angle, side), (30.0, CrossSide::Right));
+/// ```
+pub fn relative_wind(course: f64, wind_direction: f64) -> (f64, CrossSide) {
+    let angle = acute_wind_angle(wind_direction, course);
+
+    (angle.abs(), if angle >= 0.0 { CrossSide::Right } else { CrossSide::Left })
+}
+
+fn signed_wind_angle(angle: f64) -> f64 {
+    let angle = normalize_degree(angle);
 
-    round(to_degree((ws / tas * to_radian(normalize_degree(awa)).sin()).asin()), 2)
+    if angle > 180.0 {
+        angle - 360.0
+    } else {
+        angle
+    }
 }
 
 /// # Calculate Heading
@@ -74,102 +307,961 @@ pub fn wind_correction_angle(tas: f64, ws: f64, awa: f64) -> f64 {
 /// let heading = heading(90.0, 110.0, 180.0, 12.5);
 /// ```
 pub fn heading(dc: f64, tas: f64, wd: f64, ws: f64) -> f64 {
-    round(dc + wind_correction_angle(tas, ws, wd - dc), 2)
-}
+    assert_finite(dc, "dc");
+    assert_finite(wd, "wd");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn calculate_ground_speed_no_wind() {
-        let result = ground_speed(70.0, 100.0, 0.0, 0.0);
-        assert_eq!(result, 100.0);
-    }
+    round(dc + wind_correction_angle(tas, ws, acute_wind_angle(wd, dc)), 2)
+}
 
-    #[test]
-    fn calculate_ground_speed_headwind() {
-        let result = ground_speed(0.0, 100.0, 0.0, 30.0);
-        assert_eq!(result, 70.0);
-    }
+/// # Calculate Compass Heading
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC), true
+/// * `tas`: True Air Speed (TAS)
+/// * `wd`: Wind Direction (WD), true
+/// * `ws`: Wind Speed (WS)
+/// * `variation`: Magnetic variation in degrees, positive for east and negative for west
+/// * `deviation`: Compass deviation from the deviation card in degrees, positive for east and negative for west
+///
+/// returns: f64 Compass heading in degrees between 0 and 360
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let compass_heading = compass_heading(90.0, 110.0, 180.0, 12.5, 3.0, -1.0);
+/// ```
+pub fn compass_heading(dc: f64, tas: f64, wd: f64, ws: f64, variation: f64, deviation: f64) -> f64 {
+    assert_finite(variation, "variation");
+    assert_finite(deviation, "deviation");
 
-    #[test]
-    fn calculate_ground_speed_tailwind() {
-        let result = ground_speed(0.0, 100.0, 180.0, 20.0);
-        assert_eq!(result, 120.0);
-    }
+    // East is least, west is best
+    round((heading(dc, tas, wd, ws) - variation - deviation).rem_euclid(360.0), 2)
+}
 
-    #[test]
-    fn calculate_ground_speed_crosswind() {
-        let result = ground_speed(180.0, 100.0, 90.0, 10.0);
-        assert_eq!(result, 99.5);
-    }
+/// # Calculate Heading with Plausibility Warning
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC)
+/// * `tas`: True Air Speed (TAS)
+/// * `wd`: Wind Direction (WD)
+/// * `ws`: Wind Speed (WS)
+///
+/// returns: (f64, Option<Warning>) Heading and a warning if the wind correction angle is implausibly large
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (heading, warning) = heading_with_warning(90.0, 110.0, 180.0, 12.5);
+/// ```
+pub fn heading_with_warning(dc: f64, tas: f64, wd: f64, ws: f64) -> (f64, Option<Warning>) {
+    let wca = wind_correction_angle(tas, ws, acute_wind_angle(wd, dc));
+    let warning = (wca.abs() > LARGE_WIND_CORRECTION).then_some(Warning::LargeWindCorrection { wca });
 
-    #[test]
-    fn calculate_ground_speed_precision() {
-        let result = ground_speed(45.0, 90.0, 90.0, 12.0);
-        assert_eq!(result, 81.11);
-    }
+    (heading(dc, tas, wd, ws), warning)
+}
 
-    #[test]
-    fn calculate_wca_0() {
-        let result = wind_correction_angle(100.0, 20.0, 90.0);
-        assert_eq!(result, 11.54);
-    }
+/// # Calculate Heading with Check for Unflyable Courses
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC)
+/// * `tas`: True Air Speed (TAS)
+/// * `wd`: Wind Direction (WD)
+/// * `ws`: Wind Speed (WS)
+///
+/// returns: Result<f64, NavigationError> Heading, or an error if the TAS is not positive or the crosswind component
+/// exceeds the TAS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let heading = heading_checked(90.0, 110.0, 180.0, 12.5).unwrap();
+/// ```
+pub fn heading_checked(dc: f64, tas: f64, wd: f64, ws: f64) -> Result<f64, NavigationError> {
+    validate_wind_triangle(dc, tas, wd, ws)?;
 
-    #[test]
-    fn calculate_wca_1() {
-        let result = wind_correction_angle(100.0, 20.0, 450.0);
-        assert_eq!(result, 11.54);
-    }
+    Ok(heading(dc, tas, wd, ws))
+}
 
-    #[test]
-    fn calculate_wca_2() {
-        let result = wind_correction_angle(100.0, 20.0, 270.0);
-        assert_eq!(result, -11.54);
+fn validate_wind_triangle(dc: f64, tas: f64, wd: f64, ws: f64) -> Result<(), NavigationError> {
+    if tas == 0.0 {
+        return Err(NavigationError::ZeroAirspeed);
     }
 
-    #[test]
-    fn calculate_wca_3() {
-        let result = wind_correction_angle(90.0, 20.0, 60.0);
-        assert_eq!(result, 11.1);
+    if tas < 0.0 {
+        return Err(NavigationError::NegativeAirspeed { tas });
     }
 
-    #[test]
-    fn calculate_wca_4() {
-        let result = wind_correction_angle(90.0, 0.0, 0.0);
-        assert_eq!(result, 0.0);
+    if (ws / tas * to_radian(normalize_degree(wd - dc)).sin()).abs() > 1.0 {
+        return Err(NavigationError::CourseUnflyable { dc, tas, wd, ws });
     }
 
-    #[test]
-    fn calculate_wca_5() {
-        let result = wind_correction_angle(90.0, 0.0, 360.0);
-        assert_eq!(result, 0.0);
-    }
+    Ok(())
+}
 
-    #[test]
-    fn calculate_wca_6() {
-        let result = wind_correction_angle(90.0, 0.0, 40.0);
-        assert_eq!(result, 0.0);
-    }
+/// # Calculate Heading from a Wind Vector
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC)
+/// * `tas`: True Air Speed (TAS)
+/// * `wind_u`: Eastward wind component in the same unit as TAS
+/// * `wind_v`: Northward wind component in the same unit as TAS
+///
+/// returns: f64 Heading
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let heading = heading_from_wind_vector(90.0, 110.0, 0.0, 12.5);
+/// ```
+pub fn heading_from_wind_vector(dc: f64, tas: f64, wind_u: f64, wind_v: f64) -> f64 {
+    let (wd, ws) = uv_to_wind(wind_u, wind_v);
 
-    #[test]
-    fn calculate_wca_7() {
-        let result = wind_correction_angle(90.0, 40.0, 0.0);
-        assert_eq!(result, 0.0);
-    }
+    heading(dc, tas, wd, ws)
+}
 
-    #[test]
-    fn calculate_wca_8() {
-        let result = wind_correction_angle(90.0, 40.0, 180.0);
-        assert_eq!(result, 0.0);
-    }
+/// # Estimate True Air Speed (TAS) by Rule of Thumb
+///
+/// Applies the classic approximation of 2% per 1000 ft pressure altitude. It is close to the exact density based
+/// conversion in the lower altitudes, but increasingly overestimates the TAS over the GA altitude range and ignores
+/// temperature.
+///
+/// ## Arguments
+///
+/// * `ias`: Indicated Air Speed (IAS) in any unit
+/// * `pressure_altitude_ft`: Pressure altitude in ft
+///
+/// returns: f64 Estimated TAS in the same unit as IAS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let tas = tas_rule_of_thumb(100.0, 5000.0);
+/// ```
+pub fn tas_rule_of_thumb(ias: f64, pressure_altitude_ft: f64) -> f64 {
+    assert_finite(ias, "ias");
+    assert_finite(pressure_altitude_ft, "pressure_altitude_ft");
 
-    #[test]
-    fn calculate_wca_9() {
-        let result = wind_correction_angle(90.0, 20.0, -45.0);
-        assert_eq!(result, -9.04);
-    }
+    round(ias * (1.0 + 0.02 * pressure_altitude_ft / 1000.0), 2)
+}
+
+/// # Calculate Wind Components
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading (or course) in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `wind_speed`: Wind Speed (WS) in any unit
+///
+/// returns: (f64, f64) Headwind (negative for tailwind) and crosswind (positive from the right, negative from the left) in the same unit as WS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (headwind, crosswind) = wind_components(250.0, 220.0, 15.0);
+/// ```
+pub fn wind_components(runway_heading: f64, wind_direction: f64, wind_speed: f64) -> (f64, f64) {
+    assert_finite(runway_heading, "runway_heading");
+    assert_finite(wind_direction, "wind_direction");
+    assert_finite(wind_speed, "wind_speed");
+
+    let angle = to_radian(normalize_degree(wind_direction - runway_heading));
+
+    (round(wind_speed * angle.cos(), 2), round(wind_speed * angle.sin(), 2))
+}
+
+/// # Calculate Headwind Component
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading (or course) in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `wind_speed`: Wind Speed (WS) in any unit
+///
+/// returns: f64 Headwind (negative for tailwind) in the same unit as WS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let headwind = headwind_component(250.0, 220.0, 15.0);
+/// ```
+pub fn headwind_component(runway_heading: f64, wind_direction: f64, wind_speed: f64) -> f64 {
+    wind_components(runway_heading, wind_direction, wind_speed).0
+}
+
+/// # Calculate Crosswind Component
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading (or course) in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `wind_speed`: Wind Speed (WS) in any unit
+///
+/// returns: f64 Crosswind (positive from the right, negative from the left) in the same unit as WS
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let crosswind = crosswind_component(250.0, 220.0, 15.0);
+/// ```
+pub fn crosswind_component(runway_heading: f64, wind_direction: f64, wind_speed: f64) -> f64 {
+    wind_components(runway_heading, wind_direction, wind_speed).1
+}
+
+/// # Calculate Crosswind Component in Knots from Wind in m/s
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading (or course) in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `wind_speed_mps`: Wind Speed (WS) in m/s, as reported by some European sources
+///
+/// returns: f64 Crosswind (positive from the right, negative from the left) in knots
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let crosswind = crosswind_component_knots(250.0, 220.0, 8.0);
+/// ```
+pub fn crosswind_component_knots(runway_heading: f64, wind_direction: f64, wind_speed_mps: f64) -> f64 {
+    wind_components(runway_heading, wind_direction, mps_to_knots(wind_speed_mps)).1
+}
+
+/// # Calculate Classified Wind Components
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading (or course) in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `wind_speed`: Wind Speed (WS) in any unit
+///
+/// returns: WindComponents Absolute head- and crosswind in the same unit as WS, with their direction and side.
+/// A component of exactly zero is classified as headwind respectively from the right.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let components = wind_components_classified(250.0, 220.0, 15.0);
+/// assert_eq!(components.cross_side, CrossSide::Left);
+/// ```
+pub fn wind_components_classified(runway_heading: f64, wind_direction: f64, wind_speed: f64) -> WindComponents {
+    let (headwind, crosswind) = wind_components(runway_heading, wind_direction, wind_speed);
+
+    WindComponents {
+        headwind: headwind.abs(),
+        crosswind: crosswind.abs(),
+        along_track: if headwind >= 0.0 { AlongTrack::Head } else { AlongTrack::Tail },
+        cross_side: if crosswind >= 0.0 { CrossSide::Right } else { CrossSide::Left },
+    }
+}
+
+/// # Calculate Maximum Wind for a Crosswind Limit
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading in degrees
+/// * `wind_direction`: Wind Direction (WD) in degrees
+/// * `crosswind_limit`: Maximum crosswind component, e.g. the demonstrated crosswind, in any unit
+///
+/// returns: f64 Maximum wind speed in the unit of the crosswind limit, infinite for wind along the runway
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let max_wind = max_wind_for_crosswind_limit(250.0, 220.0, 15.0);
+/// assert_eq!(max_wind, 30.0);
+/// ```
+pub fn max_wind_for_crosswind_limit(runway_heading: f64, wind_direction: f64, crosswind_limit: f64) -> f64 {
+    assert_finite(runway_heading, "runway_heading");
+    assert_finite(wind_direction, "wind_direction");
+    assert_finite(crosswind_limit, "crosswind_limit");
+
+    let sin = to_radian(normalize_degree(wind_direction - runway_heading)).sin().abs();
+
+    // sin(180°) is not exactly zero in floating point
+    if approx_eq(sin, 0.0, 1e-9) {
+        return f64::INFINITY;
+    }
+
+    round(crosswind_limit / sin, 2)
+}
+
+/// # Calculate Acceptable Wind Polar
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading in degrees
+/// * `demonstrated_crosswind`: Demonstrated crosswind in any unit
+/// * `steps`: Number of evenly spaced wind directions around the compass, starting at 0°
+///
+/// returns: Vec<(f64, f64)> Wind direction in degrees and maximum acceptable wind speed in the unit of the
+/// demonstrated crosswind. The speed is capped at ten times the demonstrated crosswind for wind (almost) along the runway.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let polar = acceptable_wind_polar(250.0, 15.0, 36);
+/// ```
+pub fn acceptable_wind_polar(runway_heading: f64, demonstrated_crosswind: f64, steps: usize) -> Vec<(f64, f64)> {
+    let cap = demonstrated_crosswind * 10.0;
+
+    (0..steps)
+        .map(|step| {
+            let direction = round(step as f64 * 360.0 / steps as f64, 2);
+
+            (direction, max_wind_for_crosswind_limit(runway_heading, direction, demonstrated_crosswind).min(cap))
+        })
+        .collect()
+}
+
+/// # Calculate Leg Time
+///
+/// ## Arguments
+///
+/// * `distance`: Distance of the leg in any unit
+/// * `ground_speed`: Ground Speed (GS) in the same unit as distance per hour
+///
+/// returns: f64 Leg time in minutes
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let minutes = leg_time(45.0, 90.0);
+/// ```
+pub fn leg_time(distance: f64, ground_speed: f64) -> f64 {
+    assert_finite(distance, "distance");
+    assert_finite(ground_speed, "ground_speed");
+
+    round(distance / ground_speed * 60.0, 2)
+}
+
+/// # Calculate Leg Duration
+///
+/// Same as `leg_time`, but returns a `Duration` to make the unit unambiguous.
+///
+/// ## Arguments
+///
+/// * `distance`: Distance of the leg in any unit
+/// * `ground_speed`: Ground Speed (GS) in the same unit as distance per hour
+///
+/// returns: Option<Duration> Leg time, rounded to ms, or `None` for a negative distance or a ground speed of zero or
+/// below
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let duration = leg_duration(45.0, 90.0).unwrap();
+/// ```
+pub fn leg_duration(distance: f64, ground_speed: f64) -> Option<Duration> {
+    assert_finite(distance, "distance");
+    assert_finite(ground_speed, "ground_speed");
+
+    if distance < 0.0 || ground_speed <= 0.0 {
+        return None;
+    }
+
+    Duration::try_from_secs_f64(round(distance / ground_speed * 3600.0, 3)).ok()
+}
+
+/// # Calculate Leg Time with Turn Allowance
+///
+/// ## Arguments
+///
+/// * `distance`: Distance of the leg in any unit
+/// * `ground_speed`: Ground Speed (GS) in the same unit as distance per hour
+/// * `turn_allowance_seconds`: Fixed allowance for the turn or acceleration at the waypoint in seconds
+///
+/// returns: f64 Leg time in minutes
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let minutes = leg_time_with_turn(45.0, 90.0, 30.0);
+/// ```
+pub fn leg_time_with_turn(distance: f64, ground_speed: f64, turn_allowance_seconds: f64) -> f64 {
+    assert_finite(turn_allowance_seconds, "turn_allowance_seconds");
+
+    round(leg_time(distance, ground_speed) + turn_allowance_seconds / 60.0, 2)
+}
+
+/// # Calculate Drift Angle
+///
+/// ## Arguments
+///
+/// * `heading`: Heading in degrees
+/// * `track`: Track over ground in degrees
+///
+/// returns: f64 Drift angle in degrees between -180 and 180, positive for drift to the right. For a desired course
+/// flown with the correct heading it has the same magnitude as the WCA, but the opposite sign.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let drift = drift_angle(355.0, 5.0);
+/// assert_eq!(drift, 10.0);
+/// ```
+pub fn drift_angle(heading: f64, track: f64) -> f64 {
+    assert_finite(heading, "heading");
+    assert_finite(track, "track");
+
+    round(signed_wind_angle(track - heading), 2)
+}
+
+/// # Calculate Reciprocal Course
+///
+/// ## Arguments
+///
+/// * `course`: Course in degrees
+///
+/// returns: f64 Opposite course in degrees between 0 and 360
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let reciprocal = reciprocal(250.0);
+/// assert_eq!(reciprocal, 70.0);
+/// ```
+pub fn reciprocal(course: f64) -> f64 {
+    assert_finite(course, "course");
+
+    (course + 180.0).rem_euclid(360.0)
+}
+
+/// # Calculate Out and Back Leg Times
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC) of the outbound leg
+/// * `tas`: True Air Speed (TAS)
+/// * `wd`: Wind Direction (WD)
+/// * `ws`: Wind Speed (WS)
+/// * `distance`: Distance of one way in the unit of TAS per hour
+///
+/// returns: (f64, f64, f64) Outbound, inbound and total time in minutes
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (outbound, inbound, total) = out_and_back(90.0, 100.0, 120.0, 15.0, 30.0);
+/// ```
+pub fn out_and_back(dc: f64, tas: f64, wd: f64, ws: f64, distance: f64) -> (f64, f64, f64) {
+    let outbound = leg_time(distance, ground_speed(dc, tas, wd, ws));
+    let inbound = leg_time(distance, ground_speed(reciprocal(dc), tas, wd, ws));
+
+    (outbound, inbound, round(outbound + inbound, 2))
+}
+
+/// # Analyse a Leg
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC)
+/// * `tas`: True Air Speed (TAS)
+/// * `wd`: Wind Direction (WD)
+/// * `ws`: Wind Speed (WS)
+/// * `distance`: Distance of the leg in the unit of TAS per hour
+///
+/// returns: LegAnalysis Heading, WCA, drift angle, GS, leg time in minutes and the wind components relative to the course
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let analysis = leg_analysis(90.0, 110.0, 180.0, 12.5, 45.0);
+/// ```
+pub fn leg_analysis(dc: f64, tas: f64, wd: f64, ws: f64, distance: f64) -> LegAnalysis {
+    let ground_speed = ground_speed(dc, tas, wd, ws);
+    let (headwind, crosswind) = wind_components(dc, wd, ws);
+
+    let heading = heading(dc, tas, wd, ws);
+
+    LegAnalysis {
+        heading,
+        wind_correction_angle: wind_correction_angle(tas, ws, acute_wind_angle(wd, dc)),
+        drift_angle: drift_angle(heading, dc),
+        ground_speed,
+        leg_time: leg_time(distance, ground_speed),
+        headwind,
+        crosswind,
+    }
+}
+
+/// # Parse Wind
+///
+/// ## Arguments
+///
+/// * `s`: Wind given as "270/15", "270@15" or in the 5 digit METAR format "27015"
+///
+/// returns: Result<(f64, f64), WindParseError> Wind direction in degrees and wind speed in knots
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (wd, ws) = parse_wind("270/15").unwrap();
+/// ```
+pub fn parse_wind(s: &str) -> Result<(f64, f64), WindParseError> {
+    let input = s.trim();
+    let invalid_format = || WindParseError::InvalidFormat { input: input.to_string() };
+
+    let (direction, speed) = match input.split_once(['/', '@']) {
+        Some(parts) => parts,
+        None if input.len() == 5 && input.chars().all(|c| c.is_ascii_digit()) => input.split_at(3),
+        None => return Err(invalid_format()),
+    };
+
+    let direction: f64 = direction.trim().parse().map_err(|_| invalid_format())?;
+    // Rust also parses "inf" and "NaN", which are no wind speed
+    let speed: f64 = speed.trim().parse().ok().filter(|speed: &f64| speed.is_finite()).ok_or_else(invalid_format)?;
+
+    if !(0.0..=360.0).contains(&direction) {
+        return Err(WindParseError::DirectionOutOfRange { direction });
+    }
+
+    if speed < 0.0 {
+        return Err(WindParseError::NegativeSpeed { speed });
+    }
+
+    Ok((direction, speed))
+}
+
+/// # Convert a Direction to a Cardinal Direction
+///
+/// ## Arguments
+///
+/// * `direction`: Direction in degrees, e.g. of the wind
+///
+/// returns: &str Label of the nearest of the 16 points of the compass, e.g. "NNE"
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// assert_eq!(wind_to_cardinal(225.0), "SW");
+/// ```
+pub fn wind_to_cardinal(direction: f64) -> &'static str {
+    const POINTS: [&str; 16] = ["N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW"];
+
+    assert_finite(direction, "direction");
+
+    // Each point covers 22.5°, centered on its direction, so 348.75° and above wrap to north
+    let index = ((direction.rem_euclid(360.0) + 11.25) / 22.5) as usize % POINTS.len();
+
+    POINTS[index]
+}
+
+/// # Convert a Wind Vector to Direction and Speed
+///
+/// ## Arguments
+///
+/// * `u`: Eastward wind component, positive for wind blowing towards the east
+/// * `v`: Northward wind component, positive for wind blowing towards the north
+///
+/// returns: (f64, f64) Wind direction the wind is coming from in degrees between 0 and 360 and wind speed in the unit
+/// of the components. A calm is returned as 0° with a speed of 0.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (wd, ws) = uv_to_wind(10.0, 0.0);
+/// assert_eq!((wd, ws), (270.0, 10.0));
+/// ```
+pub fn uv_to_wind(u: f64, v: f64) -> (f64, f64) {
+    assert_finite(u, "u");
+    assert_finite(v, "v");
+
+    let speed = u.hypot(v);
+
+    if speed == 0.0 {
+        return (0.0, 0.0);
+    }
+
+    (round(to_degree((-u).atan2(-v)).rem_euclid(360.0), 2), round(speed, 2))
+}
+
+/// # Convert Direction and Speed to a Wind Vector
+///
+/// ## Arguments
+///
+/// * `wind_direction`: Wind Direction (WD) the wind is coming from in degrees
+/// * `wind_speed`: Wind Speed (WS) in any unit
+///
+/// returns: (f64, f64) Eastward and northward wind component in the unit of the wind speed
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (u, v) = wind_to_uv(270.0, 10.0);
+/// ```
+pub fn wind_to_uv(wind_direction: f64, wind_speed: f64) -> (f64, f64) {
+    assert_finite(wind_direction, "wind_direction");
+    assert_finite(wind_speed, "wind_speed");
+
+    let direction = to_radian(wind_direction);
+
+    (-wind_speed * direction.sin(), -wind_speed * direction.cos())
+}
+
+/// # Calculate Representative Climb Wind
+///
+/// ## Arguments
+///
+/// * `surface_wind`: Wind direction in degrees and speed at the bottom of the climb or descent
+/// * `top_wind`: Wind direction in degrees and speed at the top of the climb or descent
+/// * `fraction`: Position between bottom (0) and top (1), usually 2/3
+///
+/// returns: (f64, f64) Wind direction in degrees and speed in the unit of the given winds, interpolated as vectors
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (wd, ws) = representative_climb_wind((240.0, 10.0), (280.0, 30.0), 2.0 / 3.0);
+/// ```
+pub fn representative_climb_wind(surface_wind: (f64, f64), top_wind: (f64, f64), fraction: f64) -> (f64, f64) {
+    assert_finite(fraction, "fraction");
+
+    let (surface_u, surface_v) = wind_to_uv(surface_wind.0, surface_wind.1);
+    let (top_u, top_v) = wind_to_uv(top_wind.0, top_wind.1);
+
+    uv_to_wind(surface_u + (top_u - surface_u) * fraction, surface_v + (top_v - surface_v) * fraction)
+}
+
+/// # Calculate Crosswind on Final
+///
+/// The wind is interpolated linearly between the surface wind and the wind aloft by the height of the aircraft, so the
+/// crosswind changes during the approach and the flare. Above the report height the wind aloft applies.
+///
+/// ## Arguments
+///
+/// * `runway_heading`: Runway heading in degrees
+/// * `wind_aloft`: Wind direction in degrees and speed at the report height
+/// * `surface_wind`: Wind direction in degrees and speed on the runway
+/// * `height_agl`: Height of the aircraft above ground
+/// * `report_height`: Height above ground the wind aloft is reported for in the unit of the height
+///
+/// returns: Option<f64> Crosswind (positive from the right, negative from the left) in the unit of the wind speeds, or
+/// `None` for a report height of zero or below, which leaves nothing to interpolate
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let crosswind = crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 50.0, 1000.0).unwrap();
+/// ```
+pub fn crosswind_on_final(runway_heading: f64, wind_aloft: (f64, f64), surface_wind: (f64, f64), height_agl: f64, report_height: f64) -> Option<f64> {
+    assert_finite(height_agl, "height_agl");
+    assert_finite(report_height, "report_height");
+
+    if report_height <= 0.0 {
+        return None;
+    }
+
+    let (wd, ws) = representative_climb_wind(surface_wind, wind_aloft, (height_agl / report_height).clamp(0.0, 1.0));
+
+    Some(wind_components(runway_heading, wd, ws).1)
+}
+
+/// # Solve the Wind Triangle
+///
+/// Fills in the missing values of the wind triangle. Solvable are any two of the air vector (heading and TAS), the
+/// ground vector (course and GS) and the wind, as well as course, TAS and wind for heading and GS.
+///
+/// ## Arguments
+///
+/// * `known`: Known values of the wind triangle
+///
+/// returns: Result<WindTriangleSolution, NavigationError> Complete wind triangle, or an error if not enough values are
+/// known or the course can not be flown
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let known = WindTriangleInputs { course: Some(90.0), heading: Some(95.0), tas: Some(110.0), gs: Some(100.0), ..WindTriangleInputs::default() };
+/// let solution = solve(known).unwrap();
+/// ```
+pub fn solve(known: WindTriangleInputs) -> Result<WindTriangleSolution, NavigationError> {
+    let WindTriangleInputs { course, heading, tas, gs, wd, ws } = known;
+
+    let (course, heading, tas, gs, wd, ws) = match (course, heading, tas, gs, wd, ws) {
+        (Some(course), Some(heading), Some(tas), Some(gs), _, _) => {
+            let (wd, ws) = solve_wind(heading, course, tas, gs);
+
+            (course, heading, tas, gs, wd, ws)
+        }
+        (_, Some(heading), Some(tas), _, Some(wd), Some(ws)) => {
+            let (air_u, air_v) = direction_to_vector(heading, tas);
+            let (wind_u, wind_v) = wind_to_uv(wd, ws);
+            let (course, gs) = vector_to_direction(air_u + wind_u, air_v + wind_v);
+
+            (course, heading, tas, gs, wd, ws)
+        }
+        (Some(course), _, _, Some(gs), Some(wd), Some(ws)) => {
+            let (ground_u, ground_v) = direction_to_vector(course, gs);
+            let (wind_u, wind_v) = wind_to_uv(wd, ws);
+            let (heading, tas) = vector_to_direction(ground_u - wind_u, ground_v - wind_v);
+
+            (course, heading, tas, gs, wd, ws)
+        }
+        (Some(course), _, Some(tas), _, Some(wd), Some(ws)) => {
+            let heading = heading_checked(course, tas, wd, ws)?.rem_euclid(360.0);
+
+            (course, heading, tas, ground_speed(course, tas, wd, ws), wd, ws)
+        }
+        _ => return Err(NavigationError::InsufficientInputs),
+    };
+
+    Ok(WindTriangleSolution { course, heading, tas, gs, wd, ws })
+}
+
+/// # Calculate Heading and TAS for an ETA
+///
+/// The ground speed to cover the distance in the target time is combined with the wind to the air vector to fly.
+///
+/// ## Arguments
+///
+/// * `dc`: Desired Course (DC) in degrees
+/// * `wd`: Wind Direction (WD) in degrees
+/// * `ws`: Wind Speed (WS) in any unit
+/// * `distance`: Distance of the leg in the unit of WS times hours
+/// * `target_minutes`: Time to fly the leg in minutes
+///
+/// returns: Result<(f64, f64), NavigationError> Heading in degrees between 0 and 360 and required TAS in the unit of
+/// WS, or an error if the target time gives no positive ground speed or the wind alone already carries the aircraft
+/// at least at the required ground speed, which would need a heading away from the course
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (heading, tas) = heading_and_power_for_eta(90.0, 180.0, 12.5, 45.0, 25.0).unwrap();
+/// ```
+pub fn heading_and_power_for_eta(dc: f64, wd: f64, ws: f64, distance: f64, target_minutes: f64) -> Result<(f64, f64), NavigationError> {
+    assert_finite(distance, "distance");
+    assert_finite(target_minutes, "target_minutes");
+
+    let gs = distance / target_minutes * 60.0;
+    if !gs.is_finite() || gs <= 0.0 {
+        return Err(NavigationError::InvalidGroundSpeed { gs });
+    }
+
+    let known = WindTriangleInputs {
+        course: Some(dc),
+        gs: Some(gs),
+        wd: Some(wd),
+        ws: Some(ws),
+        ..WindTriangleInputs::default()
+    };
+    let solution = solve(known)?;
+
+    if solution.tas <= 0.0 {
+        return Err(NavigationError::ZeroAirspeed);
+    }
+
+    let along_course = solution.tas * to_radian(solution.heading - dc).cos();
+    if along_course <= 0.0 {
+        return Err(NavigationError::CarriedByWind { dc, gs, wd, ws });
+    }
+
+    Ok((solution.heading, solution.tas))
+}
+
+/// # Solve for the Wind
+///
+/// The wind is the difference between the ground vector (track and GS) and the air vector (heading and TAS), e.g. to
+/// recover the wind from a GPS log after a leg. Without drift it is a pure head- or tailwind.
+///
+/// ## Arguments
+///
+/// * `heading`: True heading flown in degrees
+/// * `track`: True track over ground in degrees
+/// * `tas`: True Air Speed (TAS) in any unit
+/// * `gs`: Ground Speed (GS) in the same unit as TAS
+///
+/// returns: (f64, f64) Wind direction the wind is coming from in degrees between 0 and 360 and wind speed in the unit
+/// of TAS. A calm is returned as 0° with a speed of 0.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::navigation::*;
+///
+/// let (wd, ws) = solve_wind(95.0, 90.0, 110.0, 100.0);
+/// ```
+pub fn solve_wind(heading: f64, track: f64, tas: f64, gs: f64) -> (f64, f64) {
+    let (air_u, air_v) = direction_to_vector(heading, tas);
+    let (ground_u, ground_v) = direction_to_vector(track, gs);
+
+    uv_to_wind(ground_u - air_u, ground_v - air_v)
+}
+
+// Eastward and northward components of a movement towards the given direction
+fn direction_to_vector(direction: f64, speed: f64) -> (f64, f64) {
+    assert_finite(direction, "direction");
+    assert_finite(speed, "speed");
+
+    let direction = to_radian(direction);
+
+    (speed * direction.sin(), speed * direction.cos())
+}
+
+fn vector_to_direction(u: f64, v: f64) -> (f64, f64) {
+    (round(to_degree(u.atan2(v)).rem_euclid(360.0), 2), round(u.hypot(v), 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::meteorology::{air_density, icao_temperature};
+
+    use super::*;
+
+    #[test]
+    fn calculate_ground_speed_no_wind() {
+        let result = ground_speed(70.0, 100.0, 0.0, 0.0);
+        assert_eq!(result, 100.0);
+    }
+
+    #[test]
+    fn calculate_ground_speed_headwind() {
+        let result = ground_speed(0.0, 100.0, 0.0, 30.0);
+        assert_eq!(result, 70.0);
+    }
+
+    #[test]
+    fn calculate_ground_speed_tailwind() {
+        let result = ground_speed(0.0, 100.0, 180.0, 20.0);
+        assert_eq!(result, 120.0);
+    }
+
+    #[test]
+    fn calculate_ground_speed_crosswind() {
+        let result = ground_speed(180.0, 100.0, 90.0, 10.0);
+        assert_eq!(result, 99.5);
+    }
+
+    #[test]
+    fn calculate_ground_speed_precision() {
+        let result = ground_speed(45.0, 90.0, 90.0, 12.0);
+        assert_eq!(result, 81.11);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "tas must be finite, got NaN")]
+    fn calculate_ground_speed_nan() {
+        ground_speed(70.0, f64::NAN, 0.0, 10.0);
+    }
+
+    #[test]
+    fn calculate_wca_0() {
+        let result = wind_correction_angle(100.0, 20.0, 90.0);
+        assert_eq!(result, 11.54);
+    }
+
+    #[test]
+    fn calculate_wca_1() {
+        let result = wind_correction_angle(100.0, 20.0, 450.0);
+        assert_eq!(result, 11.54);
+    }
+
+    #[test]
+    fn calculate_wca_2() {
+        let result = wind_correction_angle(100.0, 20.0, 270.0);
+        assert_eq!(result, -11.54);
+    }
+
+    #[test]
+    fn calculate_wca_3() {
+        let result = wind_correction_angle(90.0, 20.0, 60.0);
+        assert_eq!(result, 11.1);
+    }
+
+    #[test]
+    fn calculate_wca_4() {
+        let result = wind_correction_angle(90.0, 0.0, 0.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_5() {
+        let result = wind_correction_angle(90.0, 0.0, 360.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_6() {
+        let result = wind_correction_angle(90.0, 0.0, 40.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_7() {
+        let result = wind_correction_angle(90.0, 40.0, 0.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_8() {
+        let result = wind_correction_angle(90.0, 40.0, 180.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_9() {
+        let result = wind_correction_angle(90.0, 20.0, -45.0);
+        assert_eq!(result, -9.04);
+    }
 
     #[test]
     fn calculate_wca_10() {
@@ -178,15 +1270,88 @@ mod tests {
     }
 
     #[test]
-    fn calculate_wca_11() {
-        let result = wind_correction_angle(95.0, 11.2, 360.0);
+    fn calculate_wca_11() {
+        let result = wind_correction_angle(95.0, 11.2, 360.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn calculate_wca_12() {
+        let result = wind_correction_angle(95.0, 11.2, -160.0);
+        assert_eq!(result, -2.31);
+    }
+
+    #[test]
+    fn calculate_acute_wind_angle_right() {
+        let result = acute_wind_angle(20.0, 350.0);
+        assert_eq!(result, 30.0);
+    }
+
+    #[test]
+    fn calculate_acute_wind_angle_left() {
+        let result = acute_wind_angle(350.0, 20.0);
+        assert_eq!(result, -30.0);
+    }
+
+    #[test]
+    fn calculate_acute_wind_angle_270() {
+        let result = acute_wind_angle(270.0, 0.0);
+        assert_eq!(result, -90.0);
+    }
+
+    #[test]
+    fn calculate_acute_wind_angle_minus_160() {
+        let result = acute_wind_angle(30.0, 190.0);
+        assert_eq!(result, -160.0);
+    }
+
+    #[test]
+    fn calculate_acute_wind_angle_tailwind() {
+        let result = acute_wind_angle(180.0, 0.0);
+        assert_eq!(result, 180.0);
+        assert_eq!(acute_wind_angle(0.0, 180.0), 180.0);
+    }
+
+    #[test]
+    fn calculate_wca_tailwind_120() {
+        let result = wind_correction_angle(100.0, 20.0, 120.0);
+        assert_eq!(result, 9.97);
+    }
+
+    #[test]
+    fn calculate_wca_tailwind_150() {
+        let result = wind_correction_angle(100.0, 20.0, 150.0);
+        assert_eq!(result, 5.74);
+    }
+
+    #[test]
+    fn calculate_wca_tailwind_210() {
+        let result = wind_correction_angle(100.0, 20.0, 210.0);
+        assert_eq!(result, -5.74);
+    }
+
+    #[test]
+    fn calculate_wca_tailwind_240() {
+        let result = wind_correction_angle(100.0, 20.0, 240.0);
+        assert_eq!(result, -9.97);
+    }
+
+    #[test]
+    fn calculate_wca_13() {
+        let result = wind_correction_angle(95.0, 11.2, -180.0);
         assert_eq!(result, 0.0);
     }
 
     #[test]
-    fn calculate_wca_12() {
-        let result = wind_correction_angle(95.0, 11.2, -160.0);
-        assert_eq!(result, -2.31);
+    fn calculate_heading_5() {
+        let result = heading(90.0, 100.0, 0.0, 20.0);
+        assert_eq!(result, 78.46);
+    }
+
+    #[test]
+    fn calculate_heading_6() {
+        let result = heading(190.0, 95.0, 30.0, 11.2);
+        assert_eq!(result, 187.69);
     }
 
     #[test]
@@ -218,4 +1383,542 @@ mod tests {
         let result = heading(350.0, 95.0, 190.0, 10.1);
         assert_eq!(result, 347.92);
     }
+
+    #[test]
+    fn calculate_compass_heading() {
+        let result = compass_heading(0.0, 100.0, 90.0, 20.0, -3.0, 1.0);
+        assert_eq!(heading(0.0, 100.0, 90.0, 20.0), 11.54);
+        assert_eq!(result, 13.54);
+    }
+
+    #[test]
+    fn calculate_compass_heading_normalized() {
+        let result = compass_heading(358.0, 100.0, 0.0, 0.0, -3.0, 0.0);
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn calculate_heading_checked() {
+        let result = heading_checked(320.0, 100.0, 90.0, 23.0);
+        assert_eq!(result.unwrap(), heading(320.0, 100.0, 90.0, 23.0));
+    }
+
+    #[test]
+    fn calculate_heading_checked_at_limit() {
+        let result = heading_checked(0.0, 100.0, 90.0, 100.0);
+        assert_eq!(result.unwrap(), 90.0);
+    }
+
+    #[test]
+    fn calculate_heading_checked_unflyable() {
+        let result = heading_checked(0.0, 100.0, 85.0, 110.0);
+        assert!(matches!(result, Err(NavigationError::CourseUnflyable { .. })));
+    }
+
+    #[test]
+    fn calculate_heading_checked_zero_tas() {
+        let result = heading_checked(0.0, 0.0, 90.0, 10.0);
+        assert!(matches!(result, Err(NavigationError::ZeroAirspeed)));
+    }
+
+    #[test]
+    fn calculate_heading_checked_negative_tas() {
+        let result = heading_checked(0.0, -100.0, 90.0, 10.0);
+        assert!(matches!(result, Err(NavigationError::NegativeAirspeed { .. })));
+    }
+
+    #[test]
+    fn calculate_heading_with_warning_plausible() {
+        let result = heading_with_warning(90.0, 110.0, 180.0, 12.5);
+        assert_eq!(result, (heading(90.0, 110.0, 180.0, 12.5), None));
+    }
+
+    #[test]
+    fn calculate_heading_with_warning_swapped_inputs() {
+        let (heading, warning) = heading_with_warning(0.0, 12.5, 90.0, 11.0);
+        assert_eq!(heading, 61.64);
+        assert!(matches!(warning, Some(Warning::LargeWindCorrection { wca }) if wca > 30.0));
+    }
+
+    #[test]
+    fn calculate_ground_speed_checked() {
+        let result = ground_speed_checked(45.0, 90.0, 90.0, 12.0);
+        assert_eq!(result.unwrap(), 81.11);
+    }
+
+    #[test]
+    fn calculate_ground_speed_checked_zero_tas() {
+        let result = ground_speed_checked(0.0, 0.0, 0.0, 0.0);
+        assert!(matches!(result, Err(NavigationError::ZeroAirspeed)));
+    }
+
+    #[test]
+    fn calculate_ground_speed_checked_negative_tas() {
+        let result = ground_speed_checked(0.0, -90.0, 0.0, 10.0);
+        assert!(matches!(result, Err(NavigationError::NegativeAirspeed { .. })));
+    }
+
+    #[test]
+    fn tas_rule_of_thumb_sea_level() {
+        let result = tas_rule_of_thumb(100.0, 0.0);
+        assert_eq!(result, 100.0);
+    }
+
+    #[test]
+    fn tas_rule_of_thumb_5000() {
+        let result = tas_rule_of_thumb(100.0, 5000.0);
+        assert_eq!(result, 110.0);
+    }
+
+    #[test]
+    fn tas_rule_of_thumb_close_to_isa_density() {
+        // TAS = CAS / sqrt(density ratio) in the ICAO standard atmosphere
+        let pressure_altitude = feet_to_meter(5000.0);
+        let density = air_density(pressure_altitude, icao_temperature(pressure_altitude).unwrap()).unwrap();
+        let sea_level_density = air_density(0.0, icao_temperature(0.0).unwrap()).unwrap();
+        let reference = 100.0 / (density / sea_level_density).sqrt();
+
+        let result = tas_rule_of_thumb(100.0, 5000.0);
+        assert!(result > reference, "{result} does not overestimate {reference}");
+        assert!(approx_eq(result, reference, 3.0), "{result} differs from {reference}");
+    }
+
+    #[test]
+    fn wind_components_right_quartering_headwind() {
+        let result = wind_components(360.0, 30.0, 20.0);
+        assert_eq!(result, (17.32, 10.0));
+    }
+
+    #[test]
+    fn wind_components_left_quartering_tailwind() {
+        let result = wind_components(90.0, 300.0, 20.0);
+        assert_eq!(result, (-17.32, -10.0));
+    }
+
+    #[test]
+    fn wind_components_classified_head_right() {
+        let result = wind_components_classified(360.0, 30.0, 20.0);
+        assert_eq!(result, WindComponents { headwind: 17.32, crosswind: 10.0, along_track: AlongTrack::Head, cross_side: CrossSide::Right });
+    }
+
+    #[test]
+    fn wind_components_classified_head_left() {
+        let result = wind_components_classified(360.0, 330.0, 20.0);
+        assert_eq!(result, WindComponents { headwind: 17.32, crosswind: 10.0, along_track: AlongTrack::Head, cross_side: CrossSide::Left });
+    }
+
+    #[test]
+    fn wind_components_classified_tail_right() {
+        let result = wind_components_classified(360.0, 150.0, 20.0);
+        assert_eq!(result, WindComponents { headwind: 17.32, crosswind: 10.0, along_track: AlongTrack::Tail, cross_side: CrossSide::Right });
+    }
+
+    #[test]
+    fn wind_components_classified_tail_left() {
+        let result = wind_components_classified(360.0, 210.0, 20.0);
+        assert_eq!(result, WindComponents { headwind: 17.32, crosswind: 10.0, along_track: AlongTrack::Tail, cross_side: CrossSide::Left });
+    }
+
+    #[test]
+    fn calculate_leg_time() {
+        let result = leg_time(45.0, 90.0);
+        assert_eq!(result, 30.0);
+    }
+
+    #[test]
+    fn drift_angle_opposes_right_wca() {
+        let wca = wind_correction_angle(100.0, 20.0, 90.0);
+        let result = drift_angle(heading(0.0, 100.0, 90.0, 20.0), 0.0);
+        assert_eq!(wca, 11.54);
+        assert_eq!(result, -wca);
+    }
+
+    #[test]
+    fn drift_angle_across_north() {
+        let result = drift_angle(5.0, 355.0);
+        assert_eq!(result, -10.0);
+    }
+
+    #[test]
+    fn reciprocal_wraps_around_north() {
+        assert_eq!(reciprocal(90.0), 270.0);
+        assert_eq!(reciprocal(270.0), 90.0);
+        assert_eq!(reciprocal(-10.0), 170.0);
+    }
+
+    #[test]
+    fn out_and_back_headwind_out() {
+        let result = out_and_back(0.0, 100.0, 0.0, 20.0, 40.0);
+        assert_eq!(result, (30.0, 20.0, 50.0));
+        assert!(result.2 > out_and_back(0.0, 100.0, 0.0, 0.0, 40.0).2);
+    }
+
+    #[test]
+    fn calculate_leg_analysis() {
+        let result = leg_analysis(320.0, 100.0, 90.0, 23.0, 50.0);
+        let ground_speed = ground_speed(320.0, 100.0, 90.0, 23.0);
+        let (headwind, crosswind) = wind_components(320.0, 90.0, 23.0);
+        assert_eq!(result, LegAnalysis {
+            heading: heading(320.0, 100.0, 90.0, 23.0),
+            wind_correction_angle: wind_correction_angle(100.0, 23.0, 90.0 - 320.0),
+            drift_angle: -10.15,
+            ground_speed,
+            leg_time: leg_time(50.0, ground_speed),
+            headwind,
+            crosswind,
+        });
+        assert_eq!(result.heading, 330.15);
+        assert_eq!(result.wind_correction_angle, 10.15);
+    }
+
+    #[test]
+    fn parse_wind_slash() {
+        let result = parse_wind("270/15");
+        assert_eq!(result.unwrap(), (270.0, 15.0));
+    }
+
+    #[test]
+    fn parse_wind_at() {
+        let result = parse_wind("270@15");
+        assert_eq!(result.unwrap(), (270.0, 15.0));
+    }
+
+    #[test]
+    fn parse_wind_metar() {
+        let result = parse_wind("09005");
+        assert_eq!(result.unwrap(), (90.0, 5.0));
+    }
+
+    #[test]
+    fn parse_wind_invalid_direction() {
+        let result = parse_wind("370/15");
+        assert_eq!("The wind direction 370° is not within 0° and 360°", result.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn parse_wind_negative_speed() {
+        let result = parse_wind("270/-5");
+        assert!(matches!(result, Err(WindParseError::NegativeSpeed { .. })));
+    }
+
+    #[test]
+    fn parse_wind_not_finite_speed() {
+        assert!(matches!(parse_wind("270/inf"), Err(WindParseError::InvalidFormat { .. })));
+        assert!(matches!(parse_wind("270/NaN"), Err(WindParseError::InvalidFormat { .. })));
+        assert!(matches!(parse_wind("270@-infinity"), Err(WindParseError::InvalidFormat { .. })));
+    }
+
+    #[test]
+    fn parse_wind_invalid_format() {
+        let result = parse_wind("270 15");
+        assert!(matches!(result, Err(WindParseError::InvalidFormat { .. })));
+    }
+
+    #[test]
+    fn uv_to_wind_from_north() {
+        let result = uv_to_wind(0.0, -15.0);
+        assert_eq!(result, (0.0, 15.0));
+    }
+
+    #[test]
+    fn uv_to_wind_from_south_west() {
+        let result = uv_to_wind(10.0, 10.0);
+        assert_eq!(result, (225.0, 14.14));
+    }
+
+    #[test]
+    fn heading_from_zero_wind_vector() {
+        let result = heading_from_wind_vector(123.0, 100.0, 0.0, 0.0);
+        assert_eq!(result, 123.0);
+    }
+
+    #[test]
+    fn heading_from_wind_vector_matches_direction_and_speed() {
+        let result = heading_from_wind_vector(90.0, 100.0, 0.0, 20.0);
+        assert_eq!(result, heading(90.0, 100.0, 180.0, 20.0));
+        assert_eq!(result, 101.54);
+    }
+
+    #[test]
+    fn wind_correction_angle_detailed_at_limit() {
+        let result = wind_correction_angle_detailed(100.0, 100.0, 90.0);
+        assert_eq!(result, WcaResult { wca: 90.0, achievable: true });
+    }
+
+    #[test]
+    fn wind_correction_angle_detailed_beyond_limit() {
+        let result = wind_correction_angle_detailed(100.0, 110.0, -85.0);
+        assert_eq!(result, WcaResult { wca: -90.0, achievable: false });
+        assert_eq!(wind_correction_angle(100.0, 110.0, -85.0), -90.0);
+    }
+
+    #[test]
+    fn relative_wind_dead_ahead() {
+        let result = relative_wind(270.0, 270.0);
+        assert_eq!(result, (0.0, CrossSide::Right));
+    }
+
+    #[test]
+    fn relative_wind_left_quartering() {
+        let result = relative_wind(10.0, 325.0);
+        assert_eq!(result, (45.0, CrossSide::Left));
+    }
+
+    #[test]
+    fn relative_wind_right_quartering() {
+        let result = relative_wind(340.0, 25.0);
+        assert_eq!(result, (45.0, CrossSide::Right));
+    }
+
+    #[test]
+    fn leg_duration_90_minutes() {
+        let result = leg_duration(150.0, 100.0);
+        assert_eq!(result, Some(Duration::from_secs(5400)));
+    }
+
+    #[test]
+    fn leg_duration_matches_leg_time() {
+        let result = leg_duration(45.0, 90.0).unwrap();
+        assert_eq!(result.as_secs_f64() / 60.0, leg_time(45.0, 90.0));
+    }
+
+    #[test]
+    fn leg_duration_unflyable() {
+        assert_eq!(leg_duration(45.0, 0.0), None);
+        assert_eq!(leg_duration(45.0, -90.0), None);
+        assert_eq!(leg_duration(0.0, 0.0), None);
+        assert_eq!(leg_duration(-45.0, -90.0), None);
+    }
+
+    #[test]
+    fn leg_time_with_30_seconds_turn() {
+        let result = leg_time_with_turn(45.0, 90.0, 30.0);
+        assert_eq!(result, leg_time(45.0, 90.0) + 0.5);
+        assert_eq!(result, 30.5);
+    }
+
+    #[test]
+    fn max_wind_for_crosswind_limit_perpendicular() {
+        let result = max_wind_for_crosswind_limit(360.0, 270.0, 15.0);
+        assert_eq!(result, 15.0);
+    }
+
+    #[test]
+    fn max_wind_for_crosswind_limit_along_runway() {
+        let result = max_wind_for_crosswind_limit(90.0, 90.0, 15.0);
+        assert_eq!(result, f64::INFINITY);
+    }
+
+    #[test]
+    fn max_wind_for_crosswind_limit_tailwind() {
+        let result = max_wind_for_crosswind_limit(90.0, 270.0, 15.0);
+        assert_eq!(result, f64::INFINITY);
+    }
+
+    #[test]
+    fn acceptable_wind_polar_runway_aligned_and_perpendicular() {
+        let result = acceptable_wind_polar(0.0, 15.0, 4);
+        assert_eq!(result, vec![(0.0, 150.0), (90.0, 15.0), (180.0, 150.0), (270.0, 15.0)]);
+    }
+
+    #[test]
+    fn acceptable_wind_polar_no_steps() {
+        let result = acceptable_wind_polar(0.0, 15.0, 0);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn wind_to_uv_round_trip() {
+        let (u, v) = wind_to_uv(225.0, 14.14);
+        let result = uv_to_wind(u, v);
+        assert_eq!(result, (225.0, 14.14));
+    }
+
+    #[test]
+    fn representative_climb_wind_bounds() {
+        let surface_wind = (240.0, 10.0);
+        let top_wind = (280.0, 30.0);
+        assert_eq!(representative_climb_wind(surface_wind, top_wind, 0.0), surface_wind);
+        assert_eq!(representative_climb_wind(surface_wind, top_wind, 1.0), top_wind);
+    }
+
+    #[test]
+    fn representative_climb_wind_two_thirds() {
+        let result = representative_climb_wind((180.0, 10.0), (180.0, 40.0), 2.0 / 3.0);
+        assert_eq!(result, (180.0, 30.0));
+    }
+
+    #[test]
+    fn solve_for_wind() {
+        let known = WindTriangleInputs { course: Some(90.0), heading: Some(90.0), tas: Some(110.0), gs: Some(100.0), ..WindTriangleInputs::default() };
+        let result = solve(known).unwrap();
+        assert_eq!((result.wd, result.ws), (90.0, 10.0));
+    }
+
+    #[test]
+    fn solve_for_calm_wind() {
+        let known = WindTriangleInputs { course: Some(0.0), heading: Some(0.0), tas: Some(100.0), gs: Some(100.0), ..WindTriangleInputs::default() };
+        let result = solve(known).unwrap();
+        assert_eq!((result.wd, result.ws), (0.0, 0.0));
+    }
+
+    #[test]
+    fn solve_for_ground_vector() {
+        let known = WindTriangleInputs { heading: Some(0.0), tas: Some(100.0), wd: Some(270.0), ws: Some(10.0), ..WindTriangleInputs::default() };
+        let result = solve(known).unwrap();
+        assert_eq!((result.course, result.gs), (5.71, 100.5));
+    }
+
+    #[test]
+    fn solve_for_heading() {
+        let known = WindTriangleInputs { course: Some(90.0), tas: Some(110.0), wd: Some(180.0), ws: Some(12.5), ..WindTriangleInputs::default() };
+        let result = solve(known).unwrap();
+        assert_eq!(result.heading, heading(90.0, 110.0, 180.0, 12.5));
+        assert_eq!(result.gs, ground_speed(90.0, 110.0, 180.0, 12.5));
+    }
+
+    #[test]
+    fn solve_for_air_vector() {
+        let known = WindTriangleInputs { course: Some(90.0), gs: Some(100.0), wd: Some(90.0), ws: Some(10.0), ..WindTriangleInputs::default() };
+        let result = solve(known).unwrap();
+        assert_eq!((result.heading, result.tas), (90.0, 110.0));
+    }
+
+    #[test]
+    fn solve_unflyable() {
+        let known = WindTriangleInputs { course: Some(90.0), tas: Some(10.0), wd: Some(180.0), ws: Some(20.0), ..WindTriangleInputs::default() };
+        let result = solve(known);
+        assert!(matches!(result, Err(NavigationError::CourseUnflyable { .. })));
+    }
+
+    #[test]
+    fn solve_insufficient_inputs() {
+        let known = WindTriangleInputs { course: Some(90.0), tas: Some(110.0), gs: Some(100.0), ..WindTriangleInputs::default() };
+        let result = solve(known);
+        assert!(matches!(result, Err(NavigationError::InsufficientInputs)));
+    }
+
+    #[test]
+    fn crosswind_on_final_at_50_ft() {
+        let result = crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 50.0, 100.0);
+        assert_eq!(result, Some(12.5));
+    }
+
+    #[test]
+    fn crosswind_on_final_at_touchdown() {
+        let result = crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 0.0, 100.0);
+        assert_eq!(result, Some(5.0));
+    }
+
+    #[test]
+    fn crosswind_on_final_above_report_height() {
+        let result = crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 500.0, 100.0);
+        assert_eq!(result, Some(20.0));
+    }
+
+    #[test]
+    fn crosswind_on_final_without_report_height() {
+        assert_eq!(crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 0.0, 0.0), None);
+        assert_eq!(crosswind_on_final(270.0, (360.0, 20.0), (300.0, 10.0), 50.0, -100.0), None);
+    }
+
+    #[test]
+    fn wind_to_cardinal_north() {
+        assert_eq!(wind_to_cardinal(0.0), "N");
+        assert_eq!(wind_to_cardinal(360.0), "N");
+        assert_eq!(wind_to_cardinal(350.0), "N");
+        assert_eq!(wind_to_cardinal(348.75), "N");
+    }
+
+    #[test]
+    fn wind_to_cardinal_points() {
+        assert_eq!(wind_to_cardinal(22.5), "NNE");
+        assert_eq!(wind_to_cardinal(348.7), "NNW");
+        assert_eq!(wind_to_cardinal(180.0), "S");
+        assert_eq!(wind_to_cardinal(-90.0), "W");
+    }
+
+    #[test]
+    fn solve_wind_headwind() {
+        let result = solve_wind(90.0, 90.0, 110.0, 100.0);
+        assert_eq!(result, (90.0, 10.0));
+    }
+
+    #[test]
+    fn solve_wind_tailwind() {
+        let result = solve_wind(270.0, 270.0, 100.0, 115.0);
+        assert_eq!(result, (90.0, 15.0));
+    }
+
+    #[test]
+    fn solve_wind_inverse_of_heading_and_ground_speed() {
+        let heading = heading(90.0, 110.0, 180.0, 12.5);
+        let ground_speed = ground_speed(90.0, 110.0, 180.0, 12.5);
+        let (wd, ws) = solve_wind(heading, 90.0, 110.0, ground_speed);
+        assert!(approx_eq(wd, 180.0, 0.05) && approx_eq(ws, 12.5, 0.05), "{wd}° {ws}");
+    }
+
+    #[test]
+    fn wind_components_separately() {
+        assert_eq!(headwind_component(250.0, 220.0, 15.0), wind_components(250.0, 220.0, 15.0).0);
+        assert_eq!(crosswind_component(250.0, 220.0, 15.0), -7.5);
+    }
+
+    #[test]
+    fn wind_components_down_the_runway() {
+        assert_eq!(headwind_component(90.0, 90.0, 15.0), 15.0);
+        assert_eq!(crosswind_component(90.0, 90.0, 15.0), 0.0);
+    }
+
+    #[test]
+    fn wind_components_direct_tailwind() {
+        assert_eq!(headwind_component(90.0, 270.0, 15.0), -15.0);
+        assert_eq!(crosswind_component(90.0, 270.0, 15.0), 0.0);
+    }
+
+    #[test]
+    fn crosswind_component_knots_from_mps() {
+        let result = crosswind_component_knots(0.0, 90.0, 10.0);
+        assert_eq!(result, 19.44);
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_reaches_target() {
+        let (heading, tas) = heading_and_power_for_eta(90.0, 180.0, 12.5, 45.0, 25.0).unwrap();
+        assert_eq!(leg_time(45.0, ground_speed(90.0, tas, 180.0, 12.5)), 25.0);
+        assert!(approx_eq(heading, self::heading(90.0, tas, 180.0, 12.5), 0.01), "{heading}");
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_headwind() {
+        let result = heading_and_power_for_eta(90.0, 90.0, 20.0, 50.0, 30.0);
+        assert_eq!(result.unwrap(), (90.0, 120.0));
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_carried_by_wind() {
+        let result = heading_and_power_for_eta(90.0, 270.0, 20.0, 10.0, 30.0);
+        assert!(matches!(result, Err(NavigationError::ZeroAirspeed)));
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_tailwind_exceeds_ground_speed() {
+        let result = heading_and_power_for_eta(90.0, 270.0, 30.0, 10.0, 30.0);
+        assert!(matches!(result, Err(NavigationError::CarriedByWind { .. })), "{result:?}");
+
+        let result = heading_and_power_for_eta(90.0, 260.0, 30.0, 10.0, 30.0);
+        assert!(matches!(result, Err(NavigationError::CarriedByWind { .. })), "{result:?}");
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_zero_target_time() {
+        let result = heading_and_power_for_eta(90.0, 180.0, 12.5, 45.0, 0.0);
+        assert!(matches!(result, Err(NavigationError::InvalidGroundSpeed { gs }) if gs.is_infinite()));
+    }
+
+    #[test]
+    fn heading_and_power_for_eta_negative_target_time() {
+        let result = heading_and_power_for_eta(90.0, 180.0, 12.5, 45.0, -25.0);
+        assert!(matches!(result, Err(NavigationError::InvalidGroundSpeed { gs }) if gs == -108.0));
+    }
 }
@@ -1,6 +1,10 @@
 use std::f64::consts::PI;
 
 const FEET: f64 = 0.3048_f64; /* m */
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0_f64; /* 2^53 */
+const INCH_OF_MERCURY: f64 = 33.8638866667_f64; /* hPa */
+const NAUTICAL_MILE: f64 = 1852.0_f64; /* m */
+const MIN_COMPASS_ROSE_STEP: f64 = 0.1_f64; /* ° */
 
 /// # Convert meter to feet
 ///
@@ -40,6 +44,84 @@ pub fn feet_to_meter(feet: f64) -> f64 {
     feet * FEET
 }
 
+/// # Convert meter to feet and round
+///
+/// ## Arguments
+///
+/// * `meter`: Value in meters
+/// * `precision`: Precision to round
+///
+/// returns: f64 Value in feet, rounded
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let feet = meter_to_feet_rounded(50.0, 1);
+/// ```
+pub fn meter_to_feet_rounded(meter: f64, precision: u8) -> f64 {
+    round(meter_to_feet(meter), precision)
+}
+
+/// # Convert feet to meter and round
+///
+/// ## Arguments
+///
+/// * `feet`: Value in feet
+/// * `precision`: Precision to round
+///
+/// returns: f64 Value in meter, rounded
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let meter = feet_to_meter_rounded(50.0, 1);
+/// ```
+pub fn feet_to_meter_rounded(feet: f64, precision: u8) -> f64 {
+    round(feet_to_meter(feet), precision)
+}
+
+/// # Convert inch of mercury to hectopascal
+///
+/// ## Arguments
+///
+/// * `inhg`: Pressure in inHg
+///
+/// returns: f64 Pressure in hPa
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let hpa = inhg_to_hpa(29.92);
+/// ```
+pub fn inhg_to_hpa(inhg: f64) -> f64 {
+    inhg * INCH_OF_MERCURY
+}
+
+/// # Convert hectopascal to inch of mercury
+///
+/// ## Arguments
+///
+/// * `hpa`: Pressure in hPa
+///
+/// returns: f64 Pressure in inHg
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let inhg = hpa_to_inhg(1013.25);
+/// ```
+pub fn hpa_to_inhg(hpa: f64) -> f64 {
+    hpa / INCH_OF_MERCURY
+}
+
 /// # Convert to Degree
 ///
 /// ## Arguments
@@ -78,13 +160,53 @@ pub fn to_radian(value: f64) -> f64 {
     PI / 180.0_f64 * value
 }
 
+/// # Convert Turns to Degree
+/// Convenience for simulation integrations, `to_radian` and `to_degree` remain the primary angle conversions.
+///
+/// ## Arguments
+///
+/// * `turns`: Value in full turns
+///
+/// returns: f64 Value in degrees
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let degree = turns_to_degree(0.25);
+/// ```
+pub fn turns_to_degree(turns: f64) -> f64 {
+    turns * 360.0_f64
+}
+
+/// # Convert Degree to Turns
+/// Convenience for simulation integrations, `to_radian` and `to_degree` remain the primary angle conversions.
+///
+/// ## Arguments
+///
+/// * `degree`: Value in degrees
+///
+/// returns: f64 Value in full turns
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let turns = degree_to_turns(90.0);
+/// ```
+pub fn degree_to_turns(degree: f64) -> f64 {
+    degree / 360.0_f64
+}
+
 /// # Normalize Degree
 ///
 /// ## Arguments
 ///
 /// * `value`: Any degree value
 ///
-/// returns: f64 Representation of the degree value between 0 and 360
+/// returns: f64 Representation of the degree value from 0 to below 360, also for negative values
 ///
 /// # Examples
 ///
@@ -94,7 +216,130 @@ pub fn to_radian(value: f64) -> f64 {
 /// let degree = normalize_degree(370.0);
 /// ```
 pub fn normalize_degree(value: f64) -> f64 {
-    value % 360.0_f64
+    // Unlike rem_euclid, this can not return 360 for tiny negative values
+    ((value % 360.0_f64) + 360.0_f64) % 360.0_f64
+}
+
+/// # Compass Rose
+///
+/// Panics if the step is not finite or below 0.1°, which would not end or allocate an unreasonable number of ticks.
+///
+/// ## Arguments
+///
+/// * `step`: Degrees between two ticks, at least 0.1°
+///
+/// returns: Vec<f64> Normalized degree values from 0 to below 360 at the given step
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let ticks = compass_rose(30.0);
+/// ```
+pub fn compass_rose(step: f64) -> Vec<f64> {
+    assert!(step.is_finite() && step >= MIN_COMPASS_ROSE_STEP, "step must be at least {MIN_COMPASS_ROSE_STEP}°, got {step}");
+
+    (0..).map(|index| f64::from(index) * step).take_while(|&degree| degree < 360.0).map(normalize_degree).collect()
+}
+
+/// # Convert Gradient to Angle
+///
+/// ## Arguments
+///
+/// * `percent`: Gradient in percent
+///
+/// returns: f64 Angle in degrees
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let angle = gradient_to_angle(5.24);
+/// ```
+pub fn gradient_to_angle(percent: f64) -> f64 {
+    to_degree((percent / 100.0).atan())
+}
+
+/// # Convert Angle to Gradient
+///
+/// ## Arguments
+///
+/// * `degrees`: Angle in degrees
+///
+/// returns: f64 Gradient in percent
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let gradient = angle_to_gradient(3.0);
+/// ```
+pub fn angle_to_gradient(degrees: f64) -> f64 {
+    to_radian(degrees).tan() * 100.0
+}
+
+/// # Convert Knots to Feet per Minute
+///
+/// One knot equals about 101.27 ft/min, so a climb or descent gradient in percent times the ground speed in knots times
+/// 1.0127 gives the rate of climb or descent in ft/min.
+///
+/// ## Arguments
+///
+/// * `knots`: Speed in knots
+///
+/// returns: f64 Speed in ft/min
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let fpm = knots_to_fpm(90.0);
+/// ```
+pub fn knots_to_fpm(knots: f64) -> f64 {
+    meter_to_feet(knots * NAUTICAL_MILE) / 60.0
+}
+
+/// # Convert Meters per Second to Knots
+///
+/// ## Arguments
+///
+/// * `meters_per_second`: Speed in m/s
+///
+/// returns: f64 Speed in knots
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let knots = mps_to_knots(10.0);
+/// ```
+pub fn mps_to_knots(meters_per_second: f64) -> f64 {
+    meters_per_second * 3600.0 / NAUTICAL_MILE
+}
+
+/// # Convert All Values
+///
+/// ## Arguments
+///
+/// * `values`: Values to convert
+/// * `f`: Conversion to apply to each value, e.g. `meter_to_feet`
+///
+/// returns: Vec<f64> Converted values in the same order
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let feet = convert_all(&[100.0, 250.0], meter_to_feet);
+/// ```
+pub fn convert_all(values: &[f64], f: impl Fn(f64) -> f64) -> Vec<f64> {
+    values.iter().map(|&value| f(value)).collect()
 }
 
 /// # Round
@@ -104,7 +349,8 @@ pub fn normalize_degree(value: f64) -> f64 {
 /// * `number`: Number to round
 /// * `precision`: Precision to round
 ///
-/// returns: f64 Rounded value
+/// returns: f64 Rounded value. Rounding is exact as long as the scaled number `number * 10^precision` stays below
+/// 2^53, beyond that the number has no representable decimals left and is returned unchanged.
 ///
 /// # Examples
 ///
@@ -114,8 +360,101 @@ pub fn normalize_degree(value: f64) -> f64 {
 /// let rounded = round(55.5555, 2);
 /// ```
 pub fn round(number: f64, precision: u8) -> f64 {
-    let base = 10_i32.pow(precision.into()) as f64;
-    (number * base).round() / base
+    let base = 10_f64.powi(precision.into());
+    let scaled = number * base;
+
+    if !scaled.is_finite() || scaled.abs() >= MAX_EXACT_INTEGER {
+        return number;
+    }
+
+    scaled.round() / base
+}
+
+/// # Round Up
+///
+/// Rounds towards positive infinity, e.g. for distances that must never be shown optimistic.
+///
+/// ## Arguments
+///
+/// * `number`: Number to round up
+/// * `precision`: Precision to round up
+///
+/// returns: f64 Rounded up value, with the same limits as `round`
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let rounded = round_up(128.01, 0);
+/// ```
+pub fn round_up(number: f64, precision: u8) -> f64 {
+    let base = 10_f64.powi(precision.into());
+    let scaled = number * base;
+
+    if !scaled.is_finite() || scaled.abs() >= MAX_EXACT_INTEGER {
+        return number;
+    }
+
+    // Only drop the representation error of the scaling, e.g. 128.01 * 100 is a few ulps above 12801, so any larger
+    // excess is still rounded up
+    let nearest = scaled.round();
+    if (scaled - nearest).abs() <= nearest.abs() * 4.0 * f64::EPSILON {
+        return nearest / base;
+    }
+
+    scaled.ceil() / base
+}
+
+/// # Round to Nearest Increment
+///
+/// ## Arguments
+///
+/// * `value`: Value to round
+/// * `increment`: Increment to round to, e.g. 5 for fuel in liters or 100 for altitudes in feet
+///
+/// returns: f64 Nearest multiple of the increment, ties go to the even multiple. A zero increment returns the value unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// let fuel = round_to_nearest(12.6, 5.0);
+/// ```
+pub fn round_to_nearest(value: f64, increment: f64) -> f64 {
+    if increment == 0.0 {
+        return value;
+    }
+
+    (value / increment).round_ties_even() * increment
+}
+
+/// # Approximately Equal
+///
+/// ## Arguments
+///
+/// * `a`: First value
+/// * `b`: Second value
+/// * `epsilon`: Maximum allowed absolute difference
+///
+/// returns: bool Whether both values are within epsilon of each other. NaN is never equal, infinities only to themselves.
+///
+/// # Examples
+///
+/// ```
+/// use aviation_calculator::utils::*;
+///
+/// assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+/// ```
+pub fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    a == b || (a - b).abs() <= epsilon
+}
+
+/// Debug assertion that a value is finite, naming the offending input. Compiles out in release builds.
+#[inline]
+pub(crate) fn assert_finite(value: f64, name: &str) {
+    debug_assert!(value.is_finite(), "{name} must be finite, got {value}");
 }
 
 #[cfg(test)]
@@ -146,6 +485,30 @@ mod tests {
         assert_eq!(result, 1.6764000000000001);
     }
 
+    #[test]
+    fn meter_to_feet_rounded_pressure_altitude() {
+        let result = meter_to_feet_rounded(crate::meteorology::pressure_altitude_by_qnh(996.0, feet_to_meter(364.0)).unwrap(), 1);
+        assert_eq!(result, 838.2);
+    }
+
+    #[test]
+    fn feet_to_meter_rounded_5() {
+        let result = feet_to_meter_rounded(5.5, 2);
+        assert_eq!(result, 1.68);
+    }
+
+    #[test]
+    fn inhg_to_hpa_standard() {
+        let result = round(inhg_to_hpa(29.92), 2);
+        assert_eq!(result, 1013.21);
+    }
+
+    #[test]
+    fn hpa_to_inhg_standard() {
+        let result = round(hpa_to_inhg(1013.25), 2);
+        assert_eq!(result, 29.92);
+    }
+
     #[test]
     fn to_degree_1() {
         let result = to_degree(std::f64::consts::FRAC_PI_2);
@@ -158,6 +521,24 @@ mod tests {
         assert_eq!(result, std::f64::consts::FRAC_PI_2);
     }
 
+    #[test]
+    fn turns_to_degree_quarter() {
+        let result = turns_to_degree(0.25);
+        assert_eq!(result, 90.0);
+    }
+
+    #[test]
+    fn degree_to_turns_quarter() {
+        let result = degree_to_turns(90.0);
+        assert_eq!(result, 0.25);
+    }
+
+    #[test]
+    fn turns_round_trip() {
+        let result = degree_to_turns(turns_to_degree(1.75));
+        assert_eq!(result, 1.75);
+    }
+
     #[test]
     fn normalize_degree_1() {
         let result = normalize_degree(370.0);
@@ -170,9 +551,213 @@ mod tests {
         assert_eq!(result, 0.0);
     }
 
+    #[test]
+    fn normalize_degree_negative() {
+        let result = normalize_degree(-45.0);
+        assert_eq!(result, 315.0);
+    }
+
+    #[test]
+    fn normalize_degree_negative_full_turn() {
+        let result = normalize_degree(-360.0);
+        assert_eq!(result, 0.0);
+    }
+
+    #[test]
+    fn normalize_degree_negative_multiple_turns() {
+        let result = normalize_degree(-730.0);
+        assert_eq!(result, 350.0);
+    }
+
+    #[test]
+    fn compass_rose_30() {
+        let result = compass_rose(30.0);
+        assert_eq!(result.len(), 12);
+        assert_eq!(result[0], 0.0);
+        assert_eq!(result[11], 330.0);
+    }
+
+    #[test]
+    fn compass_rose_uneven_step() {
+        let result = compass_rose(7.0);
+        assert_eq!(result.len(), 52);
+        assert_eq!(result.last(), Some(&357.0));
+    }
+
+    #[test]
+    fn compass_rose_smallest_step() {
+        let result = compass_rose(MIN_COMPASS_ROSE_STEP);
+        assert_eq!(result.len(), 3600);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be at least 0.1°, got 0")]
+    fn compass_rose_zero_step() {
+        compass_rose(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be at least 0.1°, got -30")]
+    fn compass_rose_negative_step() {
+        compass_rose(-30.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be at least 0.1°, got 0.000001")]
+    fn compass_rose_tiny_step() {
+        compass_rose(0.000001);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be at least 0.1°, got NaN")]
+    fn compass_rose_nan_step() {
+        compass_rose(f64::NAN);
+    }
+
+    #[test]
+    fn gradient_to_angle_100() {
+        let result = gradient_to_angle(100.0);
+        assert_eq!(result, 45.0);
+    }
+
+    #[test]
+    fn gradient_to_angle_glide_path() {
+        let result = gradient_to_angle(5.24);
+        assert!(approx_eq(result, 3.0, 0.01), "{result}");
+    }
+
+    #[test]
+    fn knots_to_fpm_1() {
+        let result = knots_to_fpm(1.0);
+        assert_eq!(round(result, 2), 101.27);
+    }
+
+    #[test]
+    fn knots_to_fpm_glide_path_descent_rate() {
+        // 3° glide path at 90 kt GS descends at about 478 ft/min
+        let result = knots_to_fpm(90.0) * angle_to_gradient(3.0) / 100.0;
+        assert_eq!(round(result, 0), 478.0);
+    }
+
+    #[test]
+    fn mps_to_knots_10() {
+        let result = mps_to_knots(10.0);
+        assert_eq!(round(result, 2), 19.44);
+    }
+
+    #[test]
+    fn angle_to_gradient_45() {
+        let result = angle_to_gradient(45.0);
+        assert!(approx_eq(result, 100.0, 1e-9), "{result}");
+    }
+
+    #[test]
+    fn angle_to_gradient_glide_path() {
+        let result = angle_to_gradient(3.0);
+        assert!(approx_eq(result, 5.24, 0.01), "{result}");
+    }
+
+    #[test]
+    fn round_to_nearest_down() {
+        let result = round_to_nearest(12.3, 5.0);
+        assert_eq!(result, 10.0);
+    }
+
+    #[test]
+    fn round_to_nearest_up() {
+        let result = round_to_nearest(12.6, 5.0);
+        assert_eq!(result, 15.0);
+    }
+
+    #[test]
+    fn round_to_nearest_tie() {
+        let result = round_to_nearest(250.0, 100.0);
+        assert_eq!(result, 200.0);
+    }
+
+    #[test]
+    fn round_to_nearest_above_tie() {
+        let result = round_to_nearest(251.0, 100.0);
+        assert_eq!(result, 300.0);
+    }
+
+    #[test]
+    fn round_to_nearest_zero_increment() {
+        let result = round_to_nearest(12.3, 0.0);
+        assert_eq!(result, 12.3);
+    }
+
+    #[test]
+    fn approx_eq_within_epsilon() {
+        assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+        assert!(!approx_eq(1.0, 1.1, 0.01));
+    }
+
+    #[test]
+    fn approx_eq_nan() {
+        assert!(!approx_eq(f64::NAN, f64::NAN, f64::INFINITY));
+        assert!(!approx_eq(f64::NAN, 1.0, f64::INFINITY));
+    }
+
+    #[test]
+    fn approx_eq_infinity() {
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, 0.0));
+        assert!(!approx_eq(f64::INFINITY, f64::NEG_INFINITY, 0.0));
+        assert!(!approx_eq(f64::INFINITY, f64::MAX, 1e300));
+    }
+
+    #[test]
+    fn convert_all_meter_to_feet() {
+        let result = convert_all(&[0.0, 1.0, 5.5], meter_to_feet);
+        assert_eq!(result, vec![0.0, 3.280839895013123, 18.04461942257218]);
+    }
+
     #[test]
     fn round_1() {
         let result = round(55.5555, 2);
         assert_eq!(result, 55.56);
     }
+
+    #[test]
+    fn round_large_number_unchanged() {
+        let result = round(1e18, 2);
+        assert_eq!(result, 1e18);
+    }
+
+    #[test]
+    fn round_up_whole() {
+        let result = round_up(128.01, 0);
+        assert_eq!(result, 129.0);
+        assert_eq!(round(128.01, 0), 128.0);
+    }
+
+    #[test]
+    fn round_up_exact_decimals_unchanged() {
+        let result = round_up(128.01, 2);
+        assert_eq!(result, 128.01);
+    }
+
+    #[test]
+    fn round_up_just_above_step() {
+        assert_eq!(round_up(128.0000001, 0), 129.0);
+        assert_eq!(round_up(128.0100001, 2), 128.02);
+    }
+
+    #[test]
+    fn round_up_negative() {
+        let result = round_up(-128.99, 0);
+        assert_eq!(result, -128.0);
+    }
+
+    #[test]
+    fn round_overflowing_scale_unchanged() {
+        let result = round(1e300, 20);
+        assert_eq!(result, 1e300);
+    }
+
+    #[test]
+    fn round_high_precision() {
+        let result = round(0.123456789012, 10);
+        assert_eq!(result, 0.1234567890);
+    }
 }
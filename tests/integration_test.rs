@@ -1,6 +1,6 @@
 use aviation_calculator::fk9::*;
 use aviation_calculator::meteorology::pressure_altitude_by_qnh;
-use aviation_calculator::utils::{feet_to_meter, meter_to_feet, round};
+use aviation_calculator::utils::{feet_to_meter, meter_to_feet, meter_to_feet_rounded, round};
 
 #[test]
 fn common_takeoff_calculation_zellhausen() {
@@ -21,18 +21,24 @@ fn common_takeoff_calculation_frankfurt() {
 
 #[test]
 fn common_pressure_altitude() {
-    let result: f64 = pressure_altitude_by_qnh(996.0, 113.7);
+    let result: f64 = pressure_altitude_by_qnh(996.0, 113.7).unwrap();
     assert_eq!(result, 258.25);
 }
 
 #[test]
 fn pressure_altitude_in_feet() {
-    let result: f64 = round(meter_to_feet(pressure_altitude_by_qnh(996.0, feet_to_meter(364.0))), 1);
+    let result: f64 = round(meter_to_feet(pressure_altitude_by_qnh(996.0, feet_to_meter(364.0)).unwrap()), 1);
+    assert_eq!(result, 838.2);
+}
+
+#[test]
+fn pressure_altitude_in_feet_rounded() {
+    let result: f64 = meter_to_feet_rounded(pressure_altitude_by_qnh(996.0, feet_to_meter(364.0)).unwrap(), 1);
     assert_eq!(result, 838.2);
 }
 
 #[test]
 fn pressure_altitude_in_feed_check_rounding() {
-    let result: f64 = round(meter_to_feet(pressure_altitude_by_qnh(1013.25, feet_to_meter(364.0))), 1);
+    let result: f64 = round(meter_to_feet(pressure_altitude_by_qnh(1013.25, feet_to_meter(364.0)).unwrap()), 1);
     assert_eq!(result, 364.0);
 }